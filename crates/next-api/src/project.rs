@@ -1309,7 +1309,14 @@ async fn any_output_changed(
 async fn get_referenced_output_assets(
     parent: Vc<Box<dyn OutputAsset>>,
 ) -> Result<impl Iterator<Item = Vc<Box<dyn OutputAsset>>> + Send> {
-    Ok(parent.references().await?.clone_value().into_iter())
+    Ok(parent
+        .references()
+        .await?
+        .iter()
+        .chain(parent.auxiliary_assets().await?.iter())
+        .copied()
+        .collect::<Vec<_>>()
+        .into_iter())
 }
 
 #[turbo_tasks::function]
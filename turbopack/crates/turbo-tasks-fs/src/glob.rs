@@ -2,7 +2,7 @@ use std::mem::take;
 
 use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
-use turbo_tasks::{trace::TraceRawVcs, RcStr, TryJoinIterExt, Vc};
+use turbo_tasks::{trace::TraceRawVcs, RcStr, TaskInput, TryJoinIterExt, Vc};
 use unicode_segmentation::GraphemeCursor;
 
 #[derive(PartialEq, Eq, Debug, Clone, TraceRawVcs, Serialize, Deserialize)]
@@ -41,6 +41,20 @@ enum GlobPart {
 // Note: a/**/b does match a/b, so we need some special logic about path
 // separators
 
+/// Controls which part of a path a [`Glob`] has to match.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, TaskInput, TraceRawVcs, Serialize, Deserialize,
+)]
+pub enum GlobAnchoring {
+    /// The glob has to match the full path from the root, e.g. `*.ts` matches
+    /// `a.ts`, but not `src/a.ts`.
+    #[default]
+    Anchored,
+    /// The glob may match any trailing segments of the path, e.g. `*.ts`
+    /// matches both `a.ts` and `src/a.ts`.
+    Unanchored,
+}
+
 #[turbo_tasks::value]
 #[derive(Debug, Clone)]
 pub struct Glob {
@@ -83,6 +97,16 @@ impl Glob {
 
         Ok(Glob { expression })
     }
+
+    pub fn parse_with_anchoring(input: &str, anchoring: GlobAnchoring) -> Result<Glob> {
+        let mut glob = Glob::parse(input)?;
+        if anchoring == GlobAnchoring::Unanchored {
+            // An unanchored glob behaves like an anchored glob with a `**/` prefix
+            glob.expression
+                .splice(0..0, [GlobPart::AnyDirectories, GlobPart::PathSeparator]);
+        }
+        Ok(glob)
+    }
 }
 
 struct GlobMatchesIterator<'a> {
@@ -399,6 +423,14 @@ impl Glob {
         Ok(Self::cell(Glob::try_from(glob.as_str())?))
     }
 
+    #[turbo_tasks::function]
+    pub fn new_with_anchoring(glob: RcStr, anchoring: GlobAnchoring) -> Result<Vc<Self>> {
+        Ok(Self::cell(Glob::parse_with_anchoring(
+            glob.as_str(),
+            anchoring,
+        )?))
+    }
+
     #[turbo_tasks::function]
     pub async fn alternatives(globs: Vec<Vc<Glob>>) -> Result<Vc<Self>> {
         if globs.len() == 1 {
@@ -422,7 +454,7 @@ impl Glob {
 mod tests {
     use rstest::*;
 
-    use super::Glob;
+    use super::{Glob, GlobAnchoring};
 
     #[rstest]
     #[case::file("file.js", "file.js")]
@@ -516,4 +548,23 @@ mod tests {
 
         assert!(!glob.execute(path));
     }
+
+    #[rstest]
+    #[case::file("*.ts", "a.ts", true, true)]
+    #[case::nested_file("*.ts", "src/a.ts", false, true)]
+    #[case::dir_and_file("src/*.ts", "src/a.ts", true, true)]
+    #[case::nested_dir_and_file("src/*.ts", "app/src/a.ts", false, true)]
+    #[case::partial_segment("rc/*.ts", "src/a.ts", false, false)]
+    fn glob_anchoring(
+        #[case] glob: &str,
+        #[case] path: &str,
+        #[case] anchored: bool,
+        #[case] unanchored: bool,
+    ) {
+        let anchored_glob = Glob::parse_with_anchoring(glob, GlobAnchoring::Anchored).unwrap();
+        let unanchored_glob = Glob::parse_with_anchoring(glob, GlobAnchoring::Unanchored).unwrap();
+
+        assert_eq!(anchored_glob.execute(path), anchored);
+        assert_eq!(unanchored_glob.execute(path), unanchored);
+    }
 }
@@ -15,6 +15,7 @@ mod invalidator_map;
 pub mod json;
 mod mutex_map;
 mod read_glob;
+mod registry;
 mod retry;
 pub mod rope;
 pub mod source_context;
@@ -48,7 +49,8 @@ use invalidator_map::InvalidatorMap;
 use jsonc_parser::{parse_to_serde_value, ParseOptions};
 use mime::Mime;
 use read_glob::read_glob;
-pub use read_glob::ReadGlobResult;
+pub use read_glob::{ReadGlobLimits, ReadGlobResult};
+pub use registry::{FileSystemRegistry, OptionFileSystem};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::{
@@ -59,7 +61,7 @@ use tokio::{
 use tracing::Instrument;
 use turbo_tasks::{
     mark_stateful, trace::TraceRawVcs, Completion, Invalidator, RcStr, ReadRef,
-    SerializationInvalidator, ValueToString, Vc,
+    SerializationInvalidator, TryJoinIterExt, ValueToString, Vc,
 };
 use turbo_tasks_hash::{
     hash_xxh3_hash128, hash_xxh3_hash64, DeterministicHash, DeterministicHasher,
@@ -67,6 +69,7 @@ use turbo_tasks_hash::{
 use util::{extract_disk_access, join_path, normalize_path, sys_to_unix, unix_to_sys};
 pub use virtual_fs::VirtualFileSystem;
 use watcher::DiskWatcher;
+pub use watcher::WatchStats;
 
 use self::{invalidation::Write, json::UnparseableJson, mutex_map::MutexMap};
 use crate::{
@@ -299,6 +302,12 @@ impl DiskFileSystem {
         self.serialization_invalidator.invalidate();
     }
 
+    /// Starts watching the filesystem for changes.
+    ///
+    /// The OS watcher is registered and all reads that happened before are
+    /// invalidated before this returns, so reads started afterwards observe
+    /// every later change. Events are processed on a background thread, so
+    /// the invalidation caused by a change still arrives asynchronously.
     pub fn start_watching(&self) -> Result<()> {
         self.start_watching_internal(false)
     }
@@ -334,6 +343,12 @@ impl DiskFileSystem {
         self.watcher.stop_watching();
     }
 
+    /// Returns counters of the filesystem watcher, e.g. how many events were
+    /// received and how many invalidations they caused.
+    pub fn watch_stats(&self) -> WatchStats {
+        self.watcher.stats()
+    }
+
     pub async fn to_sys_path(&self, fs_path: Vc<FileSystemPath>) -> Result<PathBuf> {
         // just in case there's a windows unc path prefix we remove it with `dunce`
         let path = self.root_path();
@@ -877,10 +892,12 @@ pub struct FileSystemPath {
 }
 
 impl FileSystemPath {
+    /// Returns true if `self` is a descendant of `other`. Only whole segments
+    /// match, so `foobar` is not inside `foo`, and a path is not inside itself.
     pub fn is_inside_ref(&self, other: &FileSystemPath) -> bool {
         if self.fs == other.fs && self.path.starts_with(&*other.path) {
             if other.path.is_empty() {
-                true
+                !self.path.is_empty()
             } else {
                 self.path.as_bytes().get(other.path.len()) == Some(&b'/')
             }
@@ -889,6 +906,8 @@ impl FileSystemPath {
         }
     }
 
+    /// Like [`FileSystemPath::is_inside_ref`], but also returns true if both
+    /// paths are equal.
     pub fn is_inside_or_equal_ref(&self, other: &FileSystemPath) -> bool {
         if self.fs == other.fs && self.path.starts_with(&*other.path) {
             if other.path.is_empty() {
@@ -908,6 +927,34 @@ impl FileSystemPath {
         self.path.is_empty()
     }
 
+    /// Returns the deepest path that all of `paths` are inside of or equal to.
+    /// Returns `None` if `paths` is empty or if the paths are on different
+    /// filesystems.
+    pub async fn common_ancestor(
+        paths: Vec<Vc<FileSystemPath>>,
+    ) -> Result<Option<Vc<FileSystemPath>>> {
+        let paths = paths.iter().try_join().await?;
+        let Some((first, rest)) = paths.split_first() else {
+            return Ok(None);
+        };
+        let mut common = FileSystemPath {
+            fs: first.fs,
+            path: first.path.clone(),
+        };
+        for path in rest {
+            if path.fs != common.fs {
+                return Ok(None);
+            }
+            while !path.is_inside_or_equal_ref(&common) {
+                common.path = match common.path.rfind('/') {
+                    Some(index) => common.path[..index].into(),
+                    None => RcStr::default(),
+                };
+            }
+        }
+        Ok(Some(FileSystemPath::new_normalized(common.fs, common.path)))
+    }
+
     /// Returns the path of `inner` relative to `self`.
     ///
     /// Note: this method always strips the leading `/` from the result.
@@ -925,6 +972,10 @@ impl FileSystemPath {
         }
     }
 
+    /// Returns the relative path from `self` to `other`, like a relative
+    /// import specifier. It starts with `./` or `../`, or is `.` when both
+    /// paths are equal. Returns `None` if the paths are on different
+    /// filesystems.
     pub fn get_relative_path_to(&self, other: &FileSystemPath) -> Option<RcStr> {
         if self.fs != other.fs {
             return None;
@@ -1202,7 +1253,20 @@ impl FileSystemPath {
         glob: Vc<Glob>,
         include_dot_files: bool,
     ) -> Vc<ReadGlobResult> {
-        read_glob(self, glob, include_dot_files)
+        read_glob(self, glob, include_dot_files, ReadGlobLimits::unlimited())
+    }
+
+    /// Like [FileSystemPath::read_glob], but truncates the result when it
+    /// exceeds the given [ReadGlobLimits]. Callers need to report truncated
+    /// results, e.g. as an issue.
+    #[turbo_tasks::function]
+    pub fn read_glob_with_limits(
+        self: Vc<Self>,
+        glob: Vc<Glob>,
+        include_dot_files: bool,
+        limits: Vc<ReadGlobLimits>,
+    ) -> Vc<ReadGlobResult> {
+        read_glob(self, glob, include_dot_files, limits)
     }
 
     #[turbo_tasks::function]
@@ -1320,6 +1384,19 @@ pub async fn rebase(
     Ok(new_base.fs.root().join(new_path))
 }
 
+/// Writes `content` to `fs_path` once all of `deps` have completed.
+#[turbo_tasks::function]
+pub async fn write_after(
+    fs_path: Vc<FileSystemPath>,
+    content: Vc<FileContent>,
+    deps: Vec<Vc<Completion>>,
+) -> Result<Vc<Completion>> {
+    for dep in deps {
+        dep.await?;
+    }
+    Ok(fs_path.write(content))
+}
+
 // Not turbo-tasks functions, only delegating
 impl FileSystemPath {
     pub fn read(self: Vc<Self>) -> Vc<FileContent> {
@@ -1354,6 +1431,16 @@ impl FileSystemPath {
         self.fs().write(self, content)
     }
 
+    /// Like [`FileSystemPath::write`], but only writes after all of `deps`
+    /// have completed, e.g. the writes of the files this one refers to.
+    pub fn write_after(
+        self: Vc<Self>,
+        content: Vc<FileContent>,
+        deps: Vec<Vc<Completion>>,
+    ) -> Vc<Completion> {
+        write_after(self, content, deps)
+    }
+
     pub fn write_link(self: Vc<Self>, target: Vc<LinkContent>) -> Vc<Completion> {
         self.fs().write_link(self, target)
     }
@@ -1391,6 +1478,31 @@ impl FileSystemPath {
         Ok(FileSystemPath::new_normalized(this.fs, p.into()))
     }
 
+    /// Reads content of a directory, only including entries that are files.
+    ///
+    /// This is cached separately from [`FileSystemPath::read_dir`], so readers
+    /// are not invalidated when only directories are added or removed.
+    ///
+    /// DETERMINISM: Result is in random order. Either sort result or do not
+    /// depend on the order.
+    #[turbo_tasks::function]
+    pub async fn read_dir_files_only(self: Vc<Self>) -> Result<Vc<DirectoryContent>> {
+        read_dir_filtered(self, |entry| matches!(entry, DirectoryEntry::File(_))).await
+    }
+
+    /// Reads content of a directory, only including entries that are
+    /// directories.
+    ///
+    /// This is cached separately from [`FileSystemPath::read_dir`], so readers
+    /// are not invalidated when only files are added or removed.
+    ///
+    /// DETERMINISM: Result is in random order. Either sort result or do not
+    /// depend on the order.
+    #[turbo_tasks::function]
+    pub async fn read_dir_dirs_only(self: Vc<Self>) -> Result<Vc<DirectoryContent>> {
+        read_dir_filtered(self, |entry| matches!(entry, DirectoryEntry::Directory(_))).await
+    }
+
     #[turbo_tasks::function]
     // It is important that get_type uses read_dir and not stat/metadata.
     // - `get_type` is called very very often during resolving and stat would
@@ -1854,6 +1966,9 @@ impl DeterministicHash for FileMeta {
     }
 }
 
+/// The number of bytes inspected by [FileContent::is_probably_text].
+const TEXT_DETECTION_BYTES: usize = 8000;
+
 impl FileContent {
     pub fn new(file: File) -> Self {
         FileContent::Content(file)
@@ -1870,8 +1985,43 @@ impl FileContent {
         }
     }
 
+    /// Guesses whether the content is UTF-8 text, so callers can skip
+    /// decoding binary files. Content starting with a UTF-8 byte order mark
+    /// is text, content starting with a UTF-16 byte order mark is not, and
+    /// otherwise a null byte near the start means it's binary.
+    ///
+    /// This is only a heuristic, as valid text may contain null bytes. The
+    /// JSON parsing methods use it to bail out early, while
+    /// [FileContent::lines_ref] fails on invalid UTF-8 instead.
+    pub fn is_probably_text(&self) -> bool {
+        use std::io::Read;
+
+        let FileContent::Content(file) = self else {
+            return false;
+        };
+        let mut prefix = Vec::with_capacity(TEXT_DETECTION_BYTES);
+        if file
+            .read()
+            .take(TEXT_DETECTION_BYTES as u64)
+            .read_to_end(&mut prefix)
+            .is_err()
+        {
+            return false;
+        }
+        if prefix.starts_with(b"\xEF\xBB\xBF") {
+            return true;
+        }
+        if prefix.starts_with(b"\xFF\xFE") || prefix.starts_with(b"\xFE\xFF") {
+            return false;
+        }
+        !prefix.contains(&0)
+    }
+
     pub fn parse_json_ref(&self) -> FileJsonContent {
         match self {
+            FileContent::Content(_) if !self.is_probably_text() => {
+                FileJsonContent::unparseable("binary content is not json text")
+            }
             FileContent::Content(file) => {
                 let de = &mut serde_json::Deserializer::from_reader(file.read());
                 match serde_path_to_error::deserialize(de) {
@@ -1887,6 +2037,9 @@ impl FileContent {
 
     pub fn parse_json_with_comments_ref(&self) -> FileJsonContent {
         match self {
+            FileContent::Content(_) if !self.is_probably_text() => {
+                FileJsonContent::unparseable("binary content is not json text")
+            }
             FileContent::Content(file) => match file.content.to_str() {
                 Ok(string) => match parse_to_serde_value(
                     &string,
@@ -1914,6 +2067,9 @@ impl FileContent {
 
     pub fn parse_json5_ref(&self) -> FileJsonContent {
         match self {
+            FileContent::Content(_) if !self.is_probably_text() => {
+                FileJsonContent::unparseable("binary content is not json text")
+            }
             FileContent::Content(file) => match file.content.to_str() {
                 Ok(string) => match parse_to_serde_value(
                     &string,
@@ -2173,6 +2329,36 @@ impl DirectoryContent {
     pub fn not_found() -> Vc<Self> {
         Self::cell(DirectoryContent::NotFound)
     }
+
+    /// Returns the entries of the directory in lexicographic order of their
+    /// names. The order of [DirectoryContent::Entries] itself is arbitrary, so
+    /// this should be used whenever the order leaks into results.
+    pub fn sorted_entries(&self) -> Vec<(&RcStr, &DirectoryEntry)> {
+        match self {
+            DirectoryContent::Entries(entries) => {
+                let mut entries = entries.iter().collect::<Vec<_>>();
+                entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+                entries
+            }
+            DirectoryContent::NotFound => Vec::new(),
+        }
+    }
+}
+
+async fn read_dir_filtered(
+    path: Vc<FileSystemPath>,
+    filter: impl Fn(&DirectoryEntry) -> bool,
+) -> Result<Vc<DirectoryContent>> {
+    Ok(match &*path.read_dir().await? {
+        DirectoryContent::NotFound => DirectoryContent::not_found(),
+        DirectoryContent::Entries(entries) => DirectoryContent::new(
+            entries
+                .iter()
+                .filter(|(_, entry)| filter(entry))
+                .map(|(name, entry)| (name.clone(), *entry))
+                .collect(),
+        ),
+    })
 }
 
 #[turbo_tasks::value(shared)]
@@ -2247,6 +2433,18 @@ pub fn register() {
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            LazyLock,
+        },
+        time::Duration,
+    };
+
+    use tokio::{sync::Notify, time::timeout};
+    use turbo_tasks::TurboTasks;
+    use turbo_tasks_memory::MemoryBackend;
+
     use super::*;
 
     #[tokio::test]
@@ -2356,4 +2554,497 @@ mod tests {
         .await
         .unwrap()
     }
+
+    static COUNT_FILES_EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+    #[turbo_tasks::function]
+    async fn count_files(dir: Vc<FileSystemPath>) -> Result<Vc<usize>> {
+        COUNT_FILES_EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+        let DirectoryContent::Entries(entries) = &*dir.read_dir_files_only().await? else {
+            bail!("directory not found");
+        };
+        Ok(Vc::cell(entries.len()))
+    }
+
+    #[tokio::test]
+    async fn read_dir_files_only_ignores_new_directories() {
+        crate::register();
+
+        let scratch = tempfile::tempdir().unwrap();
+        std::fs::write(scratch.path().join("file.txt"), "content").unwrap();
+        let root: RcStr = scratch.path().to_str().unwrap().into();
+        let subdir = scratch.path().join("subdir");
+
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let fs = DiskFileSystem::new("test".into(), root, Vec::new());
+            let dir = fs.root();
+            assert_eq!(*count_files(dir).strongly_consistent().await?, 1);
+            let executions = COUNT_FILES_EXECUTIONS.load(Ordering::SeqCst);
+
+            std::fs::create_dir(subdir).unwrap();
+            fs.await?.invalidate();
+
+            // the unfiltered read sees the new directory...
+            let DirectoryContent::Entries(entries) = &*dir.read_dir().strongly_consistent().await?
+            else {
+                bail!("directory not found");
+            };
+            assert_eq!(entries.len(), 2);
+            let DirectoryContent::Entries(entries) =
+                &*dir.read_dir_dirs_only().strongly_consistent().await?
+            else {
+                bail!("directory not found");
+            };
+            assert_eq!(entries.len(), 1);
+
+            // ...but readers of the files-only content aren't invalidated
+            assert_eq!(*count_files(dir).strongly_consistent().await?, 1);
+            assert_eq!(COUNT_FILES_EXECUTIONS.load(Ordering::SeqCst), executions);
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Released by [write_after_waits_for_dependencies] to let
+    /// [gated_write] proceed.
+    static WRITE_GATE: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+    #[turbo_tasks::function]
+    async fn gated_write(path: Vc<FileSystemPath>) -> Result<Vc<Completion>> {
+        WRITE_GATE.notified().await;
+        Ok(path.write(File::from("chunk").into()))
+    }
+
+    #[tokio::test]
+    async fn write_after_waits_for_dependencies() {
+        crate::register();
+
+        let scratch = tempfile::tempdir().unwrap();
+        let root: RcStr = scratch.path().to_str().unwrap().into();
+        let chunk_path = scratch.path().join("chunk.js");
+        let manifest_path = scratch.path().join("manifest.json");
+
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let fs = DiskFileSystem::new("test".into(), root, Vec::new());
+            let chunk = fs.root().join("chunk.js".into());
+            let manifest = fs.root().join("manifest.json".into());
+
+            let manifest_written = manifest.write_after(
+                File::from("[\"chunk.js\"]").into(),
+                vec![gated_write(chunk)],
+            );
+
+            // the dependency is blocked, so the manifest must not be written yet
+            let pending =
+                timeout(Duration::from_millis(200), async { manifest_written.await }).await;
+            assert!(pending.is_err(), "the manifest was written too early");
+            assert!(!chunk_path.exists());
+            assert!(!manifest_path.exists());
+
+            WRITE_GATE.notify_one();
+            manifest_written.await?;
+            assert_eq!(std::fs::read_to_string(&chunk_path)?, "chunk");
+            assert_eq!(std::fs::read_to_string(&manifest_path)?, "[\"chunk.js\"]");
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn sorted_entries() {
+        fn content<'a>(names: impl Iterator<Item = &'a &'a str>) -> DirectoryContent {
+            DirectoryContent::Entries(
+                names
+                    .map(|name| (RcStr::from(*name), DirectoryEntry::Error))
+                    .collect(),
+            )
+        }
+
+        let names = [
+            "b.js", "a.js", "c", "_app.js", "B.js", "index.js", "a", "z.json",
+        ];
+        let expected = [
+            "B.js", "_app.js", "a", "a.js", "b.js", "c", "index.js", "z.json",
+        ];
+
+        // the result must not depend on the insertion order
+        for dir in [content(names.iter()), content(names.iter().rev())] {
+            let sorted = dir
+                .sorted_entries()
+                .into_iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(sorted, expected);
+        }
+
+        assert!(DirectoryContent::NotFound.sorted_entries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn read_glob_is_ordered() {
+        crate::register();
+
+        let scratch = tempfile::tempdir().unwrap();
+        for name in ["c.js", "a.js", "b.js", "d/z.js", "d/y.js", "_e/x.js"] {
+            let path = scratch.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        let root: RcStr = scratch.path().to_str().unwrap().into();
+
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let fs = DiskFileSystem::new("test".into(), root, Vec::new());
+            let result = fs
+                .root()
+                .read_glob(Glob::new("**/*.js".into()), false)
+                .await?;
+            assert_eq!(
+                result.results.keys().collect::<Vec<_>>(),
+                ["a.js", "b.js", "c.js"]
+            );
+            assert_eq!(result.inner.keys().collect::<Vec<_>>(), ["_e", "d"]);
+            assert_eq!(
+                result.inner["d"].await?.results.keys().collect::<Vec<_>>(),
+                ["d/y.js", "d/z.js"]
+            );
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn common_ancestor() {
+        crate::register();
+
+        turbo_tasks_testing::VcStorage::with(async {
+            let fs = Vc::upcast(VirtualFileSystem::new());
+            let other_fs = Vc::upcast(VirtualFileSystem::new());
+            let path = |path: &str| FileSystemPath::new_normalized(fs, path.into());
+            let common_ancestor = |paths: Vec<Vc<FileSystemPath>>| async move {
+                let ancestor = FileSystemPath::common_ancestor(paths).await?;
+                anyhow::Ok(match ancestor {
+                    Some(ancestor) => Some(ancestor.await?.path.clone()),
+                    None => None,
+                })
+            };
+
+            // nested paths
+            assert_eq!(
+                common_ancestor(vec![path("src/app"), path("src/app/page.js")]).await?,
+                Some("src/app".into())
+            );
+            // sibling paths
+            assert_eq!(
+                common_ancestor(vec![path("src/app/page.js"), path("src/app/layout.js")]).await?,
+                Some("src/app".into())
+            );
+            assert_eq!(
+                common_ancestor(vec![path("src/app"), path("src/application")]).await?,
+                Some("src".into())
+            );
+            assert_eq!(
+                common_ancestor(vec![path("src/index.js"), path("public/logo.png")]).await?,
+                Some("".into())
+            );
+            assert_eq!(
+                common_ancestor(vec![path("src/index.js")]).await?,
+                Some("src/index.js".into())
+            );
+            // mixed filesystems
+            assert_eq!(
+                common_ancestor(vec![
+                    path("src/index.js"),
+                    FileSystemPath::new_normalized(other_fs, "src/index.js".into()),
+                ])
+                .await?,
+                None
+            );
+            assert_eq!(common_ancestor(vec![]).await?, None);
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn read_glob_limits() {
+        crate::register();
+
+        let scratch = tempfile::tempdir().unwrap();
+        for name in ["a.js", "b.js", "c.js", "d.js"] {
+            std::fs::write(scratch.path().join(name), "").unwrap();
+        }
+        std::fs::create_dir_all(scratch.path().join("x/y/z")).unwrap();
+        std::fs::write(scratch.path().join("x/y/z/e.js"), "").unwrap();
+        let root: RcStr = scratch.path().to_str().unwrap().into();
+
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let fs = DiskFileSystem::new("test".into(), root, Vec::new());
+            let glob = Glob::new("**/*.js".into());
+
+            let result = fs.root().read_glob(glob, false);
+            assert!(!*result.is_truncated().await?);
+            assert_eq!(*result.match_count().await?, 5);
+
+            let limits = |max_entries| {
+                ReadGlobLimits {
+                    max_depth: 64,
+                    max_entries,
+                }
+                .cell()
+            };
+
+            let result = fs.root().read_glob_with_limits(glob, false, limits(2));
+            let value = result.await?;
+            assert!(value.truncated);
+            assert_eq!(value.results.len(), 2);
+            assert_eq!(*result.match_count().await?, 2);
+
+            // exactly as many matches as allowed
+            let result = fs.root().read_glob_with_limits(glob, false, limits(5));
+            assert!(!*result.is_truncated().await?);
+            assert_eq!(*result.match_count().await?, 5);
+
+            // the limit applies to all directories together
+            let result = fs.root().read_glob_with_limits(glob, false, limits(4));
+            assert!(!result.await?.truncated);
+            assert!(*result.is_truncated().await?);
+            assert_eq!(*result.match_count().await?, 4);
+
+            // `x/y/z` is too deep to be read
+            let result = fs.root().read_glob_with_limits(
+                glob,
+                false,
+                ReadGlobLimits {
+                    max_depth: 1,
+                    max_entries: 100,
+                }
+                .cell(),
+            );
+            assert!(!result.await?.truncated);
+            assert!(*result.is_truncated().await?);
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn is_inside() {
+        crate::register();
+
+        turbo_tasks_testing::VcStorage::with(async {
+            let fs = Vc::upcast(VirtualFileSystem::new());
+            let path = |path: &str| FileSystemPath::new_normalized(fs, path.into());
+
+            let root = path("").await?;
+            let foo = path("foo").await?;
+            let foo_bar = path("foo/bar").await?;
+            let foobar = path("foobar").await?;
+
+            assert!(foo_bar.is_inside_ref(&foo));
+            assert!(foo_bar.is_inside_or_equal_ref(&foo));
+            assert!(!foobar.is_inside_ref(&foo));
+            assert!(!foobar.is_inside_or_equal_ref(&foo));
+
+            assert!(!foo.is_inside_ref(&foo));
+            assert!(foo.is_inside_or_equal_ref(&foo));
+            assert!(!foo.is_inside_ref(&foo_bar));
+            assert!(!foo.is_inside_or_equal_ref(&foo_bar));
+
+            assert!(foo.is_inside_ref(&root));
+            assert!(foo_bar.is_inside_ref(&root));
+            assert!(!root.is_inside_ref(&root));
+            assert!(root.is_inside_or_equal_ref(&root));
+
+            let other_fs = Vc::upcast(VirtualFileSystem::new());
+            let other_foo_bar = FileSystemPath::new_normalized(other_fs, "foo/bar".into()).await?;
+            assert!(!other_foo_bar.is_inside_ref(&foo));
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_relative_path_to() {
+        crate::register();
+
+        turbo_tasks_testing::VcStorage::with(async {
+            let fs = Vc::upcast(VirtualFileSystem::new());
+            let path = |path: &str| FileSystemPath::new_normalized(fs, path.into());
+
+            let cases = [
+                // identical
+                ("", "", "."),
+                ("a", "a", "."),
+                ("a/b", "a/b", "."),
+                // prefix
+                ("a", "a/b/c", "./b/c"),
+                ("", "a/b", "./a/b"),
+                // suffix
+                ("a/b", "a", ".."),
+                ("a/b/c", "a", "../.."),
+                ("a/b", "", "../.."),
+                // sibling
+                ("a/b", "a/c", "../c"),
+                ("a/b/c", "a/d/e", "../../d/e"),
+                ("a", "b", "../b"),
+                // segment boundaries
+                ("a", "ab", "../ab"),
+                ("ab", "a/b", "../a/b"),
+            ];
+            for (from, to, expected) in cases {
+                let relative = path(from).await?.get_relative_path_to(&*path(to).await?);
+                assert_eq!(
+                    relative.as_deref(),
+                    Some(expected),
+                    "relative path from {from:?} to {to:?}"
+                );
+            }
+
+            let other_fs = Vc::upcast(VirtualFileSystem::new());
+            let other_a = FileSystemPath::new_normalized(other_fs, "a".into()).await?;
+            assert_eq!(path("a").await?.get_relative_path_to(&other_a), None);
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn start_watching_observes_later_writes() {
+        crate::register();
+
+        let scratch = tempfile::tempdir().unwrap();
+        let file_path = scratch.path().join("file.txt");
+        std::fs::write(&file_path, "before").unwrap();
+        let root: RcStr = scratch.path().to_str().unwrap().into();
+
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let fs = DiskFileSystem::new("test".into(), root, Vec::new());
+            fs.await?.start_watching()?;
+
+            let file = fs.root().join("file.txt".into());
+            let read = |file: Vc<FileSystemPath>| async move {
+                let content = file.read().strongly_consistent().await?;
+                let Some(file) = content.as_content() else {
+                    bail!("file not found");
+                };
+                anyhow::Ok(file.content().to_str()?.into_owned())
+            };
+            assert_eq!(read(file).await?, "before");
+
+            std::fs::write(&file_path, "after").unwrap();
+
+            // the watcher invalidates the read asynchronously
+            let mut attempts = 0;
+            while read(file).await? != "after" {
+                attempts += 1;
+                if attempts > 100 {
+                    bail!("the write was not observed");
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+
+            fs.await?.stop_watching();
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn watch_stats_count_events_and_invalidations() {
+        crate::register();
+
+        let scratch = tempfile::tempdir().unwrap();
+        let file_path = scratch.path().join("file.txt");
+        std::fs::write(&file_path, "before").unwrap();
+        let root: RcStr = scratch.path().to_str().unwrap().into();
+
+        let tt = TurboTasks::new(MemoryBackend::default());
+        tt.run_once(async move {
+            let fs = DiskFileSystem::new("test".into(), root, Vec::new());
+            fs.await?.start_watching()?;
+
+            fs.root()
+                .join("file.txt".into())
+                .read()
+                .strongly_consistent()
+                .await?;
+            std::fs::write(&file_path, "after").unwrap();
+
+            // the watcher thread processes the event asynchronously
+            let mut attempts = 0;
+            loop {
+                let stats = fs.await?.watch_stats();
+                if stats.events_received > 0 && stats.invalidations > 0 {
+                    assert_eq!(stats.rescans, 0);
+                    break;
+                }
+                attempts += 1;
+                if attempts > 100 {
+                    bail!("the write was not counted: {stats:?}");
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+
+            fs.await?.stop_watching();
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn is_probably_text() {
+        let content = |bytes: &[u8]| FileContent::new(File::from(bytes));
+
+        assert!(content(b"").is_probably_text());
+        assert!(content("const a = \"ä\";".as_bytes()).is_probably_text());
+        assert!(content(b"\xEF\xBB\xBF{}").is_probably_text());
+        // UTF-16 is text, but can't be decoded as UTF-8
+        assert!(!content(b"\xFF\xFEa\x00b\x00").is_probably_text());
+        assert!(!content(b"\xFE\xFF\x00a\x00b").is_probably_text());
+        assert!(!content(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR").is_probably_text());
+        assert!(!FileContent::NotFound.is_probably_text());
+
+        let png = content(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR");
+        assert!(matches!(
+            png.parse_json_ref(),
+            FileJsonContent::Unparseable(_)
+        ));
+        assert!(matches!(
+            png.parse_json_with_comments_ref(),
+            FileJsonContent::Unparseable(_)
+        ));
+        assert!(matches!(
+            content(b"{\"a\": 1}").parse_json_ref(),
+            FileJsonContent::Content(_)
+        ));
+
+        // The heuristic is opt-in, so valid text with a null byte can still be
+        // read as lines.
+        assert!(!content(b"a\x00b").is_probably_text());
+        assert!(matches!(
+            content(b"a\x00b").lines_ref(),
+            FileLinesContent::Lines(_)
+        ));
+    }
 }
@@ -1,28 +1,104 @@
-use std::collections::HashMap;
-
 use anyhow::Result;
+use indexmap::IndexMap;
 use turbo_tasks::{RcStr, Vc};
 
-use crate::{glob::Glob, DirectoryContent, DirectoryEntry, FileSystemPath};
+use crate::{glob::Glob, DirectoryEntry, FileSystemPath};
 
 #[turbo_tasks::value]
 #[derive(Default, Debug)]
 pub struct ReadGlobResult {
-    pub results: HashMap<String, DirectoryEntry>,
-    pub inner: HashMap<String, Vc<ReadGlobResult>>,
+    pub results: IndexMap<String, DirectoryEntry>,
+    pub inner: IndexMap<String, Vc<ReadGlobResult>>,
+    /// Whether matches in this directory were left out because a
+    /// [ReadGlobLimits] limit was hit.
+    pub truncated: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl ReadGlobResult {
+    /// Returns true if this result or any nested result was truncated.
+    #[turbo_tasks::function]
+    pub async fn is_truncated(self: Vc<Self>) -> Result<Vc<bool>> {
+        let this = self.await?;
+        if this.truncated {
+            return Ok(Vc::cell(true));
+        }
+        for inner in this.inner.values() {
+            if *inner.is_truncated().await? {
+                return Ok(Vc::cell(true));
+            }
+        }
+        Ok(Vc::cell(false))
+    }
+
+    /// Returns the number of matches in this result and all nested results.
+    #[turbo_tasks::function]
+    pub async fn match_count(self: Vc<Self>) -> Result<Vc<u32>> {
+        let this = self.await?;
+        let mut count = this.results.len() as u32;
+        for inner in this.inner.values() {
+            count += *inner.match_count().await?;
+        }
+        Ok(Vc::cell(count))
+    }
+}
+
+/// Limits that keep a glob over a huge directory tree from exhausting memory.
+/// Results exceeding a limit are truncated, which is reported by
+/// [ReadGlobResult::is_truncated].
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Copy, Debug)]
+pub struct ReadGlobLimits {
+    /// The maximum number of nested directories to descend into.
+    pub max_depth: u32,
+    /// The maximum number of matches in the whole result.
+    pub max_entries: u32,
+}
+
+impl Default for ReadGlobLimits {
+    fn default() -> Self {
+        ReadGlobLimits {
+            max_depth: 64,
+            max_entries: 100_000,
+        }
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ReadGlobLimits {
+    #[turbo_tasks::function]
+    pub fn default_limits() -> Vc<Self> {
+        Self::default().cell()
+    }
+
+    /// Limits that are never hit, so the result is never truncated.
+    #[turbo_tasks::function]
+    pub fn unlimited() -> Vc<Self> {
+        ReadGlobLimits {
+            max_depth: u32::MAX,
+            max_entries: u32::MAX,
+        }
+        .cell()
+    }
 }
 
 /// Reads matches of a glob pattern.
 ///
-/// DETERMINISM: Result is in random order. Either sort result or do not depend
-/// on the order.
+/// Matches are ordered by the names of the directory entries, see
+/// [DirectoryContent::sorted_entries](crate::DirectoryContent::sorted_entries).
 #[turbo_tasks::function(fs)]
 pub async fn read_glob(
     directory: Vc<FileSystemPath>,
     glob: Vc<Glob>,
     include_dot_files: bool,
+    limits: Vc<ReadGlobLimits>,
 ) -> Result<Vc<ReadGlobResult>> {
-    read_glob_internal("", directory, glob, include_dot_files).await
+    let result = read_glob_internal("", directory, glob, include_dot_files, 0, limits).await?;
+    let mut remaining = limits.await?.max_entries;
+    if remaining == u32::MAX {
+        return Ok(result);
+    }
+    truncate(result, &mut remaining).await
 }
 
 #[turbo_tasks::function(fs)]
@@ -31,8 +107,10 @@ async fn read_glob_inner(
     directory: Vc<FileSystemPath>,
     glob: Vc<Glob>,
     include_dot_files: bool,
+    depth: u32,
+    limits: Vc<ReadGlobLimits>,
 ) -> Result<Vc<ReadGlobResult>> {
-    read_glob_internal(&prefix, directory, glob, include_dot_files).await
+    read_glob_internal(&prefix, directory, glob, include_dot_files, depth, limits).await
 }
 
 async fn read_glob_internal(
@@ -40,43 +118,80 @@ async fn read_glob_internal(
     directory: Vc<FileSystemPath>,
     glob: Vc<Glob>,
     include_dot_files: bool,
+    depth: u32,
+    limits: Vc<ReadGlobLimits>,
 ) -> Result<Vc<ReadGlobResult>> {
     let dir = directory.read_dir().await?;
     let mut result = ReadGlobResult::default();
     let glob_value = glob.await?;
-    match &*dir {
-        DirectoryContent::Entries(entries) => {
-            for (segment, entry) in entries.iter() {
-                if !include_dot_files && segment.starts_with('.') {
-                    continue;
-                }
-                let entry = entry.resolve_symlink().await?;
-                match entry {
-                    DirectoryEntry::Directory(path) => {
-                        let full_path = format!("{prefix}{segment}");
-                        let full_path_prefix: RcStr = format!("{full_path}/").into();
-                        if glob_value.execute(&full_path) {
-                            result
-                                .results
-                                .insert(full_path.clone(), DirectoryEntry::Directory(path));
-                        }
-                        if glob_value.execute(&full_path_prefix) {
-                            result.inner.insert(
-                                full_path,
-                                read_glob_inner(full_path_prefix, path, glob, include_dot_files),
-                            );
-                        }
-                    }
-                    entry => {
-                        let full_path = format!("{prefix}{segment}");
-                        if glob_value.execute(&full_path) {
-                            result.results.insert(full_path, entry);
-                        }
-                    }
+    let max_depth = limits.await?.max_depth;
+    for (segment, entry) in dir.sorted_entries() {
+        if !include_dot_files && segment.starts_with('.') {
+            continue;
+        }
+        let entry = entry.resolve_symlink().await?;
+        let full_path = format!("{prefix}{segment}");
+        if glob_value.execute(&full_path) {
+            result.results.insert(full_path.clone(), entry);
+        }
+        if let DirectoryEntry::Directory(path) = entry {
+            let full_path_prefix: RcStr = format!("{full_path}/").into();
+            if glob_value.execute(&full_path_prefix) {
+                if depth >= max_depth {
+                    result.truncated = true;
+                } else {
+                    result.inner.insert(
+                        full_path,
+                        read_glob_inner(
+                            full_path_prefix,
+                            path,
+                            glob,
+                            include_dot_files,
+                            depth + 1,
+                            limits,
+                        ),
+                    );
                 }
             }
         }
-        DirectoryContent::NotFound => {}
     }
     Ok(ReadGlobResult::cell(result))
 }
+
+/// Keeps the first `remaining` matches of `result` in path order and marks the
+/// results that lost matches as truncated. The entry limit is applied to the
+/// merged result, so the cached per-directory results don't depend on it.
+async fn truncate(result: Vc<ReadGlobResult>, remaining: &mut u32) -> Result<Vc<ReadGlobResult>> {
+    let count = *result.match_count().await?;
+    if count <= *remaining {
+        *remaining -= count;
+        return Ok(result);
+    }
+    let this = result.await?;
+    let mut truncated = ReadGlobResult {
+        truncated: this.truncated,
+        ..Default::default()
+    };
+    let mut paths = this
+        .results
+        .keys()
+        .chain(this.inner.keys())
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths.dedup();
+    for path in paths {
+        if let Some(entry) = this.results.get(path) {
+            if *remaining == 0 {
+                truncated.truncated = true;
+            } else {
+                *remaining -= 1;
+                truncated.results.insert(path.clone(), *entry);
+            }
+        }
+        if let Some(&inner) = this.inner.get(path) {
+            let inner = Box::pin(truncate(inner, remaining)).await?;
+            truncated.inner.insert(path.clone(), inner);
+        }
+    }
+    Ok(ReadGlobResult::cell(truncated))
+}
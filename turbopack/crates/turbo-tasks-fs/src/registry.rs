@@ -0,0 +1,108 @@
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use turbo_tasks::{RcStr, Vc};
+
+use crate::{FileSystem, FileSystemPath, FileSystemPathOption};
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionFileSystem(Option<Vc<Box<dyn FileSystem>>>);
+
+/// Maps names, like `project`, `node_modules` or `output`, to filesystems.
+///
+/// This allows integrations to look up a filesystem by name instead of passing
+/// [`Vc<Box<dyn FileSystem>>`]s around manually.
+#[turbo_tasks::value]
+#[derive(Default)]
+pub struct FileSystemRegistry {
+    file_systems: IndexMap<RcStr, Vc<Box<dyn FileSystem>>>,
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystemRegistry {
+    #[turbo_tasks::function]
+    pub fn empty() -> Vc<Self> {
+        Self::default().cell()
+    }
+
+    /// Returns a new registry which additionally maps `name` to `fs`. Fails if
+    /// `name` is already registered.
+    #[turbo_tasks::function]
+    pub async fn register(
+        self: Vc<Self>,
+        name: RcStr,
+        fs: Vc<Box<dyn FileSystem>>,
+    ) -> Result<Vc<Self>> {
+        let mut file_systems = self.await?.file_systems.clone();
+        if file_systems.contains_key(&name) {
+            bail!("a filesystem named \"{name}\" is already registered");
+        }
+        file_systems.insert(name, fs);
+        Ok(FileSystemRegistry { file_systems }.cell())
+    }
+
+    /// Returns the filesystem registered as `name`, if any.
+    #[turbo_tasks::function]
+    pub async fn get(self: Vc<Self>, name: RcStr) -> Result<Vc<OptionFileSystem>> {
+        Ok(Vc::cell(self.await?.file_systems.get(&name).copied()))
+    }
+
+    /// Returns the root of the filesystem registered as `name`, if any.
+    #[turbo_tasks::function]
+    pub async fn root(self: Vc<Self>, name: RcStr) -> Result<Vc<FileSystemPathOption>> {
+        Ok(Vc::cell(
+            self.await?.file_systems.get(&name).map(|fs| fs.root()),
+        ))
+    }
+
+    /// Returns the name the filesystem of `path` is registered as, if any.
+    #[turbo_tasks::function]
+    pub async fn name_of(self: Vc<Self>, path: Vc<FileSystemPath>) -> Result<Vc<Option<RcStr>>> {
+        let fs = path.await?.fs;
+        Ok(Vc::cell(
+            self.await?
+                .file_systems
+                .iter()
+                .find(|(_, registered)| **registered == fs)
+                .map(|(name, _)| name.clone()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_tasks::Vc;
+
+    use super::FileSystemRegistry;
+    use crate::{FileSystem, VirtualFileSystem};
+
+    #[tokio::test]
+    async fn register_and_get() {
+        crate::register();
+
+        turbo_tasks_testing::VcStorage::with(async {
+            let project = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new());
+            let output = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new());
+            let registry = FileSystemRegistry::empty()
+                .register("project".into(), project)
+                .register("output".into(), output);
+
+            assert_eq!(*registry.get("project".into()).await?, Some(project));
+            assert_eq!(*registry.get("output".into()).await?, Some(output));
+            assert_eq!(*registry.get("node_modules".into()).await?, None);
+
+            let output_root = registry.root("output".into()).await?.unwrap();
+            assert_eq!(output_root.await?.fs, output);
+
+            let path = project.root().join("src/index.js".into());
+            assert_eq!(registry.name_of(path).await?.as_deref(), Some("project"));
+            let other = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new());
+            assert_eq!(registry.name_of(other.root()).await?.as_deref(), None);
+
+            assert!(registry.register("project".into(), output).await.is_err());
+
+            anyhow::Ok(())
+        })
+        .await
+        .unwrap()
+    }
+}
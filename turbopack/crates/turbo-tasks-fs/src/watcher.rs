@@ -3,7 +3,8 @@ use std::{
     mem::take,
     path::{Path, PathBuf},
     sync::{
-        mpsc::{channel, Receiver, TryRecvError},
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{sync_channel, Receiver, TryRecvError, TrySendError},
         Arc, Mutex,
     },
     time::Duration,
@@ -42,6 +43,9 @@ pub(crate) struct DiskWatcher {
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     #[serde(skip)]
     watching: dashmap::DashSet<PathBuf>,
+
+    #[serde(skip)]
+    counters: WatchCounters,
 }
 
 impl DiskWatcher {
@@ -134,11 +138,22 @@ impl DiskWatcher {
             return Ok(());
         }
 
-        // Create a channel to receive the events.
-        let (tx, rx) = channel();
+        // Create a bounded channel to receive the events. When the watch thread
+        // falls behind, events are dropped and the next batch invalidates the
+        // whole root instead.
+        let (tx, rx) = sync_channel(EVENT_QUEUE_CAPACITY);
+        let overflowed = Arc::new(AtomicBool::new(false));
+        let event_handler = {
+            let overflowed = overflowed.clone();
+            move |event: notify::Result<notify::Event>| {
+                if let Err(TrySendError::Full(_)) = tx.try_send(event) {
+                    overflowed.store(true, Ordering::Release);
+                }
+            }
+        };
         // Create a watcher object, delivering debounced events.
         // The notification back-end is selected based on the platform.
-        let mut watcher = RecommendedWatcher::new(tx, Config::default())?;
+        let mut watcher = RecommendedWatcher::new(event_handler, Config::default())?;
         // Add a path to be watched. All files and directories at that path and
         // below will be monitored for changes.
         #[cfg(any(target_os = "macos", target_os = "windows"))]
@@ -181,6 +196,7 @@ impl DiskWatcher {
         spawn_thread(move || {
             self.watch_thread(
                 rx,
+                overflowed,
                 root_path,
                 report_invalidation_reason,
                 invalidation_lock,
@@ -206,19 +222,14 @@ impl DiskWatcher {
     fn watch_thread(
         &self,
         rx: Receiver<notify::Result<notify::Event>>,
+        overflowed: Arc<AtomicBool>,
         root_path: PathBuf,
         report_invalidation_reason: Option<(RcStr, PathBuf)>,
         invalidation_lock: Arc<RwLock<()>>,
         invalidator_map: Arc<InvalidatorMap>,
         dir_invalidator_map: Arc<InvalidatorMap>,
     ) {
-        let mut batched_invalidate_path = HashSet::new();
-        let mut batched_invalidate_path_dir = HashSet::new();
-        let mut batched_invalidate_path_and_children = HashSet::new();
-        let mut batched_invalidate_path_and_children_dir = HashSet::new();
-
-        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
-        let mut batched_new_paths = HashSet::new();
+        let mut batch = WatchBatch::new(root_path.clone(), MAX_BATCHED_PATHS);
 
         'outer: loop {
             let mut event = rx.recv().or(Err(TryRecvError::Disconnected));
@@ -240,106 +251,26 @@ impl DiskWatcher {
                             return;
                         }
 
-                        // [NOTE] there is attrs in the `Event` struct, which contains few
-                        // more metadata like process_id who triggered the event,
-                        // or the source we may able to utilize later.
-                        match kind {
-                            // [NOTE] Observing `ModifyKind::Metadata(MetadataKind::Any)` is
-                            // not a mistake, fix for PACK-2437.
-                            // In here explicitly subscribes to the `ModifyKind::Data` which
-                            // indicates file content changes - in case of fsevents backend,
-                            // this is `kFSEventStreamEventFlagItemModified`.
-                            // Also meanwhile we subscribe to ModifyKind::Metadata as well.
-                            // This is due to in some cases fsevents does not emit explicit
-                            // kFSEventStreamEventFlagItemModified kernel events,
-                            // but only emits kFSEventStreamEventFlagItemInodeMetaMod. While
-                            // this could cause redundant invalidation,
-                            // it's the way to reliably detect file content changes.
-                            // ref other implementation, i.e libuv does same thing to
-                            // trigger UV_CHANEGS https://github.com/libuv/libuv/commit/73cf3600d75a5884b890a1a94048b8f3f9c66876#diff-e12fdb1f404f1c97bbdcc0956ac90d7db0d811d9fa9ca83a3deef90c937a486cR95-R99
-                            EventKind::Modify(
-                                ModifyKind::Data(_) | ModifyKind::Metadata(MetadataKind::Any),
-                            ) => {
-                                batched_invalidate_path.extend(paths.clone());
-                            }
-                            EventKind::Create(_) => {
-                                batched_invalidate_path_and_children.extend(paths.clone());
-                                batched_invalidate_path_and_children_dir.extend(paths.clone());
-                                paths.iter().for_each(|path| {
-                                    if let Some(parent) = path.parent() {
-                                        batched_invalidate_path_dir.insert(PathBuf::from(parent));
-                                    }
-                                });
-
-                                #[cfg(not(any(target_os = "macos", target_os = "windows")))]
-                                batched_new_paths.extend(paths.clone());
-                            }
-                            EventKind::Remove(_) => {
-                                batched_invalidate_path_and_children.extend(paths.clone());
-                                batched_invalidate_path_and_children_dir.extend(paths.clone());
-                                paths.iter().for_each(|path| {
-                                    if let Some(parent) = path.parent() {
-                                        batched_invalidate_path_dir.insert(PathBuf::from(parent));
-                                    }
-                                });
-                            }
-                            // A single event emitted with both the `From` and `To` paths.
-                            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
-                                // For the rename::both, notify provides an array of paths
-                                // in given order
-                                if let [source, destination, ..] = &paths[..] {
-                                    batched_invalidate_path_and_children.insert(source.clone());
-                                    if let Some(parent) = source.parent() {
-                                        batched_invalidate_path_dir.insert(PathBuf::from(parent));
-                                    }
-                                    batched_invalidate_path_and_children
-                                        .insert(destination.clone());
-                                    if let Some(parent) = destination.parent() {
-                                        batched_invalidate_path_dir.insert(PathBuf::from(parent));
-                                    }
-                                    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
-                                    batched_new_paths.insert(destination.clone());
-                                } else {
-                                    // If we hit here, we expect this as a bug either in
-                                    // notify or system weirdness.
-                                    panic!(
-                                        "Rename event does not contain source and destination \
-                                         paths {:#?}",
-                                        paths
-                                    );
-                                }
-                            }
-                            // We expect `RenameMode::Both` to cover most of the cases we
-                            // need to invalidate,
-                            // but we also check other RenameModes
-                            // to cover cases where notify couldn't match the two rename
-                            // events.
-                            EventKind::Any
-                            | EventKind::Modify(ModifyKind::Any | ModifyKind::Name(..)) => {
-                                batched_invalidate_path.extend(paths.clone());
-                                batched_invalidate_path_and_children.extend(paths.clone());
-                                batched_invalidate_path_and_children_dir.extend(paths.clone());
-                                for parent in paths.iter().filter_map(|path| path.parent()) {
-                                    batched_invalidate_path_dir.insert(PathBuf::from(parent));
-                                }
-                            }
-                            EventKind::Modify(ModifyKind::Metadata(..) | ModifyKind::Other)
-                            | EventKind::Access(_)
-                            | EventKind::Other => {
-                                // ignored
-                            }
+                        self.counters
+                            .events_received
+                            .fetch_add(1, Ordering::Relaxed);
+                        if !batch.add_event(kind, paths) {
+                            self.counters
+                                .events_coalesced
+                                .fetch_add(1, Ordering::Relaxed);
                         }
                     }
                     // Error raised by notify watcher itself
                     Ok(Err(notify::Error { kind, paths })) => {
                         println!("watch error ({:?}): {:?} ", paths, kind);
 
-                        if paths.is_empty() {
-                            batched_invalidate_path_and_children.insert(root_path.clone());
-                            batched_invalidate_path_and_children_dir.insert(root_path.clone());
-                        } else {
-                            batched_invalidate_path_and_children.extend(paths.clone());
-                            batched_invalidate_path_and_children_dir.extend(paths.clone());
+                        self.counters
+                            .events_received
+                            .fetch_add(1, Ordering::Relaxed);
+                        if !batch.add_error(paths) {
+                            self.counters
+                                .events_coalesced
+                                .fetch_add(1, Ordering::Relaxed);
                         }
                     }
                     Err(TryRecvError::Disconnected) => {
@@ -367,43 +298,297 @@ impl DiskWatcher {
                 event = rx.try_recv();
             }
 
+            if overflowed.swap(false, Ordering::Acquire) {
+                // Some events of this batch didn't fit into the channel, so we
+                // can't know which paths changed
+                batch.fall_back_to_rescan();
+            }
+            if batch.rescan {
+                self.counters.rescans.fetch_add(1, Ordering::Relaxed);
+            }
+
             // We need to start watching first before invalidating the changed paths
             #[cfg(not(any(target_os = "macos", target_os = "windows")))]
             {
-                for path in batched_new_paths.drain() {
+                if batch.rescan {
+                    // The created paths of this batch were not recorded, so any of the watched
+                    // directories might have been recreated.
+                    let watching = self
+                        .watching
+                        .iter()
+                        .map(|path| path.key().clone())
+                        .collect::<Vec<_>>();
+                    for path in watching {
+                        let _ = self.restore_if_watching(&path, &root_path);
+                    }
+                }
+                for path in batch.new_paths.drain() {
                     let _ = self.restore_if_watching(&path, &root_path);
                 }
             }
+            batch.rescan = false;
 
             let _lock = invalidation_lock.blocking_write();
+            let mut invalidations = 0;
             {
                 let mut invalidator_map = invalidator_map.lock().unwrap();
-                invalidate_path(
+                invalidations += invalidate_path(
                     &report_invalidation_reason,
                     &mut invalidator_map,
-                    batched_invalidate_path.drain(),
+                    batch.invalidate_path.drain(),
                 );
-                invalidate_path_and_children_execute(
+                invalidations += invalidate_path_and_children_execute(
                     &report_invalidation_reason,
                     &mut invalidator_map,
-                    batched_invalidate_path_and_children.drain(),
+                    batch.invalidate_path_and_children.drain(),
                 );
             }
             {
                 let mut dir_invalidator_map = dir_invalidator_map.lock().unwrap();
-                invalidate_path(
+                invalidations += invalidate_path(
                     &report_invalidation_reason,
                     &mut dir_invalidator_map,
-                    batched_invalidate_path_dir.drain(),
+                    batch.invalidate_path_dir.drain(),
                 );
-                invalidate_path_and_children_execute(
+                invalidations += invalidate_path_and_children_execute(
                     &report_invalidation_reason,
                     &mut dir_invalidator_map,
-                    batched_invalidate_path_and_children_dir.drain(),
+                    batch.invalidate_path_and_children_dir.drain(),
                 );
             }
+            self.counters
+                .invalidations
+                .fetch_add(invalidations, Ordering::Relaxed);
         }
     }
+
+    /// Returns a snapshot of the counters of the watcher thread.
+    pub(crate) fn stats(&self) -> WatchStats {
+        WatchStats {
+            events_received: self.counters.events_received.load(Ordering::Relaxed),
+            events_coalesced: self.counters.events_coalesced.load(Ordering::Relaxed),
+            invalidations: self.counters.invalidations.load(Ordering::Relaxed),
+            rescans: self.counters.rescans.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The number of events the channel between the OS watcher and the watch
+/// thread can hold. Further events are dropped and force a rescan.
+const EVENT_QUEUE_CAPACITY: usize = 16_384;
+
+/// The maximum number of distinct paths a single batch of watch events may
+/// collect. Beyond that (e.g. on a `git checkout` touching a huge tree) the
+/// batch falls back to invalidating everything below the root, so memory stays
+/// bounded and the event channel keeps draining quickly.
+const MAX_BATCHED_PATHS: usize = 10_000;
+
+#[derive(Default)]
+struct WatchCounters {
+    events_received: AtomicU64,
+    events_coalesced: AtomicU64,
+    invalidations: AtomicU64,
+    rescans: AtomicU64,
+}
+
+/// Counters of the [DiskFileSystem][crate::DiskFileSystem] watcher since it
+/// was created.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WatchStats {
+    /// Events received from the OS watcher, including errors.
+    pub events_received: u64,
+    /// Events that didn't add a path to their batch, because the paths were
+    /// already batched or the batch fell back to a rescan.
+    pub events_coalesced: u64,
+    /// Invalidators that were fired.
+    pub invalidations: u64,
+    /// Batches that exceeded the backlog limit or the event channel and
+    /// invalidated the whole root.
+    pub rescans: u64,
+}
+
+/// The paths collected from a batch of watch events, waiting to be
+/// invalidated.
+struct WatchBatch {
+    root_path: PathBuf,
+    max_paths: usize,
+    /// Set when the batch collected more than `max_paths` paths or events were
+    /// dropped by the channel. The batch then only contains the root and
+    /// further events are dropped.
+    rescan: bool,
+    invalidate_path: HashSet<PathBuf>,
+    invalidate_path_dir: HashSet<PathBuf>,
+    invalidate_path_and_children: HashSet<PathBuf>,
+    invalidate_path_and_children_dir: HashSet<PathBuf>,
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    new_paths: HashSet<PathBuf>,
+}
+
+impl WatchBatch {
+    fn new(root_path: PathBuf, max_paths: usize) -> Self {
+        Self {
+            root_path,
+            max_paths,
+            rescan: false,
+            invalidate_path: HashSet::new(),
+            invalidate_path_dir: HashSet::new(),
+            invalidate_path_and_children: HashSet::new(),
+            invalidate_path_and_children_dir: HashSet::new(),
+            #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+            new_paths: HashSet::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        let len = self.invalidate_path.len()
+            + self.invalidate_path_dir.len()
+            + self.invalidate_path_and_children.len()
+            + self.invalidate_path_and_children_dir.len();
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        let len = len + self.new_paths.len();
+        len
+    }
+
+    /// Adds the paths of an event to the batch. Returns `false` when the event
+    /// was coalesced into the existing batch.
+    fn add_event(&mut self, kind: EventKind, paths: Vec<PathBuf>) -> bool {
+        if self.rescan {
+            return false;
+        }
+        let len = self.len();
+
+        // [NOTE] there is attrs in the `Event` struct, which contains few
+        // more metadata like process_id who triggered the event,
+        // or the source we may able to utilize later.
+        match kind {
+            // [NOTE] Observing `ModifyKind::Metadata(MetadataKind::Any)` is
+            // not a mistake, fix for PACK-2437.
+            // In here explicitly subscribes to the `ModifyKind::Data` which
+            // indicates file content changes - in case of fsevents backend,
+            // this is `kFSEventStreamEventFlagItemModified`.
+            // Also meanwhile we subscribe to ModifyKind::Metadata as well.
+            // This is due to in some cases fsevents does not emit explicit
+            // kFSEventStreamEventFlagItemModified kernel events,
+            // but only emits kFSEventStreamEventFlagItemInodeMetaMod. While
+            // this could cause redundant invalidation,
+            // it's the way to reliably detect file content changes.
+            // ref other implementation, i.e libuv does same thing to
+            // trigger UV_CHANEGS https://github.com/libuv/libuv/commit/73cf3600d75a5884b890a1a94048b8f3f9c66876#diff-e12fdb1f404f1c97bbdcc0956ac90d7db0d811d9fa9ca83a3deef90c937a486cR95-R99
+            EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Metadata(MetadataKind::Any)) => {
+                self.invalidate_path.extend(paths.clone());
+            }
+            EventKind::Create(_) => {
+                self.invalidate_path_and_children.extend(paths.clone());
+                self.invalidate_path_and_children_dir.extend(paths.clone());
+                paths.iter().for_each(|path| {
+                    if let Some(parent) = path.parent() {
+                        self.invalidate_path_dir.insert(PathBuf::from(parent));
+                    }
+                });
+
+                #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+                self.new_paths.extend(paths.clone());
+            }
+            EventKind::Remove(_) => {
+                self.invalidate_path_and_children.extend(paths.clone());
+                self.invalidate_path_and_children_dir.extend(paths.clone());
+                paths.iter().for_each(|path| {
+                    if let Some(parent) = path.parent() {
+                        self.invalidate_path_dir.insert(PathBuf::from(parent));
+                    }
+                });
+            }
+            // A single event emitted with both the `From` and `To` paths.
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                // For the rename::both, notify provides an array of paths
+                // in given order
+                if let [source, destination, ..] = &paths[..] {
+                    self.invalidate_path_and_children.insert(source.clone());
+                    if let Some(parent) = source.parent() {
+                        self.invalidate_path_dir.insert(PathBuf::from(parent));
+                    }
+                    self.invalidate_path_and_children
+                        .insert(destination.clone());
+                    if let Some(parent) = destination.parent() {
+                        self.invalidate_path_dir.insert(PathBuf::from(parent));
+                    }
+                    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+                    self.new_paths.insert(destination.clone());
+                } else {
+                    // If we hit here, we expect this as a bug either in
+                    // notify or system weirdness.
+                    panic!(
+                        "Rename event does not contain source and destination paths {:#?}",
+                        paths
+                    );
+                }
+            }
+            // We expect `RenameMode::Both` to cover most of the cases we
+            // need to invalidate,
+            // but we also check other RenameModes
+            // to cover cases where notify couldn't match the two rename
+            // events.
+            EventKind::Any | EventKind::Modify(ModifyKind::Any | ModifyKind::Name(..)) => {
+                self.invalidate_path.extend(paths.clone());
+                self.invalidate_path_and_children.extend(paths.clone());
+                self.invalidate_path_and_children_dir.extend(paths.clone());
+                for parent in paths.iter().filter_map(|path| path.parent()) {
+                    self.invalidate_path_dir.insert(PathBuf::from(parent));
+                }
+            }
+            EventKind::Modify(ModifyKind::Metadata(..) | ModifyKind::Other)
+            | EventKind::Access(_)
+            | EventKind::Other => {
+                // ignored
+            }
+        }
+
+        self.check_backlog();
+        self.len() != len
+    }
+
+    /// Adds the paths of an error raised by the watcher itself. Without paths
+    /// the whole root is invalidated.
+    fn add_error(&mut self, paths: Vec<PathBuf>) -> bool {
+        if self.rescan {
+            return false;
+        }
+        let len = self.len();
+        if paths.is_empty() {
+            self.invalidate_path_and_children
+                .insert(self.root_path.clone());
+            self.invalidate_path_and_children_dir
+                .insert(self.root_path.clone());
+        } else {
+            self.invalidate_path_and_children.extend(paths.clone());
+            self.invalidate_path_and_children_dir.extend(paths);
+        }
+        self.check_backlog();
+        self.len() != len
+    }
+
+    /// Falls back to invalidating everything below the root once the batch
+    /// grew beyond `max_paths`.
+    fn check_backlog(&mut self) {
+        if self.len() > self.max_paths {
+            self.fall_back_to_rescan();
+        }
+    }
+
+    /// Replaces the batch with an invalidation of everything below the root.
+    fn fall_back_to_rescan(&mut self) {
+        self.invalidate_path.clear();
+        self.invalidate_path_dir.clear();
+        self.invalidate_path_and_children.clear();
+        self.invalidate_path_and_children_dir.clear();
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        self.new_paths.clear();
+        self.invalidate_path_and_children
+            .insert(self.root_path.clone());
+        self.invalidate_path_and_children_dir
+            .insert(self.root_path.clone());
+        self.rescan = true;
+    }
 }
 
 #[instrument(parent = None, level = "info", name = "DiskFileSystem file change", skip_all, fields(name = display(path.display())))]
@@ -425,28 +610,90 @@ fn invalidate_path(
     report_invalidation_reason: &Option<(RcStr, PathBuf)>,
     invalidator_map: &mut HashMap<String, HashSet<Invalidator>>,
     paths: impl Iterator<Item = PathBuf>,
-) {
+) -> u64 {
+    let mut count = 0;
     for path in paths {
         let key = path_to_key(&path);
         if let Some(invalidators) = invalidator_map.remove(&key) {
+            count += invalidators.len() as u64;
             invalidators
                 .into_iter()
                 .for_each(|i| invalidate(report_invalidation_reason, &path, i));
         }
     }
+    count
 }
 
 fn invalidate_path_and_children_execute(
     report_invalidation_reason: &Option<(RcStr, PathBuf)>,
     invalidator_map: &mut HashMap<String, HashSet<Invalidator>>,
     paths: impl Iterator<Item = PathBuf>,
-) {
+) -> u64 {
+    let mut count = 0;
     for path in paths {
         let path_key = path_to_key(&path);
         for (_, invalidators) in invalidator_map.extract_if(|key, _| key.starts_with(&path_key)) {
+            count += invalidators.len() as u64;
             invalidators
                 .into_iter()
                 .for_each(|i| invalidate(report_invalidation_reason, &path, i));
         }
     }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use notify::{event::CreateKind, EventKind};
+
+    use super::WatchBatch;
+
+    #[test]
+    fn batch_falls_back_to_rescan() {
+        let root_path = PathBuf::from("/project");
+        let mut batch = WatchBatch::new(root_path.clone(), 100);
+
+        for i in 0..10_000 {
+            let path = root_path.join(format!("dir/file{i}.js"));
+            batch.add_event(EventKind::Create(CreateKind::File), vec![path]);
+            assert!(batch.len() <= 100);
+        }
+
+        assert!(batch.rescan);
+        assert_eq!(batch.invalidate_path_and_children.len(), 1);
+        assert!(batch.invalidate_path_and_children.contains(&root_path));
+        assert!(batch.invalidate_path_and_children_dir.contains(&root_path));
+        assert!(batch.invalidate_path.is_empty());
+        assert!(batch.invalidate_path_dir.is_empty());
+    }
+
+    #[test]
+    fn batch_coalesces_repeated_events() {
+        let root_path = PathBuf::from("/project");
+        let mut batch = WatchBatch::new(root_path.clone(), 100);
+        let path = root_path.join("file.js");
+
+        assert!(batch.add_event(EventKind::Create(CreateKind::File), vec![path.clone()]));
+        assert!(!batch.add_event(EventKind::Create(CreateKind::File), vec![path]));
+        assert!(!batch.rescan);
+    }
+
+    #[test]
+    fn overflow_falls_back_to_rescan() {
+        let root_path = PathBuf::from("/project");
+        let mut batch = WatchBatch::new(root_path.clone(), 100);
+
+        batch.add_event(
+            EventKind::Create(CreateKind::File),
+            vec![root_path.join("file.js")],
+        );
+        batch.fall_back_to_rescan();
+
+        assert!(batch.rescan);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.invalidate_path_and_children.contains(&root_path));
+        assert!(batch.invalidate_path_and_children_dir.contains(&root_path));
+    }
 }
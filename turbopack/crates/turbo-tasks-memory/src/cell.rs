@@ -1,4 +1,7 @@
-use std::{fmt::Debug, mem::replace};
+use std::{
+    fmt::Debug,
+    mem::{replace, take},
+};
 
 use turbo_tasks::{
     backend::CellContent,
@@ -160,6 +163,8 @@ impl Cell {
     /// If clean = true, the task inputs weren't changes since the last
     /// execution and can be assumed to produce the same content again.
     ///
+    /// Returns the dependent tasks that were notified.
+    ///
     /// Safety: This funtion does not check if the type of the content is the
     /// same as the type of the cell. It is the caller's responsibility to
     /// ensure that the content is of the correct type.
@@ -168,7 +173,7 @@ impl Cell {
         content: CellContent,
         clean: bool,
         turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>,
-    ) {
+    ) -> TaskIdSet {
         match &self.state {
             CellState::Empty => {}
             CellState::Computing { event } => {
@@ -177,7 +182,7 @@ impl Cell {
                     // We can assume that the task is deterministic and produces the same content
                     // again. No need to notify dependent tasks.
                     self.state = CellState::Value { content };
-                    return;
+                    return TaskIdSet::default();
                 }
             }
             CellState::TrackedValueless => {
@@ -185,24 +190,25 @@ impl Cell {
                     // We can assume that the task is deterministic and produces the same content
                     // again. No need to notify dependent tasks.
                     self.state = CellState::Value { content };
-                    return;
+                    return TaskIdSet::default();
                 }
             }
             CellState::Value {
                 content: cell_content,
             } => {
                 if content == *cell_content {
-                    return;
+                    return TaskIdSet::default();
                 }
             }
         }
         self.state = CellState::Value { content };
         // Assigning to a cell will invalidate all dependent tasks as the content might
         // have changed.
-        if !self.dependent_tasks.is_empty() {
-            turbo_tasks.schedule_notify_tasks_set(&self.dependent_tasks);
-            self.dependent_tasks.clear();
+        let dependent_tasks = take(&mut self.dependent_tasks);
+        if !dependent_tasks.is_empty() {
+            turbo_tasks.schedule_notify_tasks_set(&dependent_tasks);
         }
+        dependent_tasks
     }
 
     pub fn empty(
@@ -18,4 +18,6 @@ mod task;
 mod task_statistics;
 
 pub use memory_backend::MemoryBackend;
-pub use task_statistics::{TaskStatistics, TaskStatisticsApi};
+pub use task_statistics::{
+    DurationPercentiles, InvalidationCause, InvalidationSource, TaskStatistics, TaskStatisticsApi,
+};
@@ -1,5 +1,7 @@
 use std::{
     borrow::{Borrow, Cow},
+    collections::HashMap,
+    fmt::Write,
     future::Future,
     hash::{BuildHasher, BuildHasherDefault, Hash},
     num::NonZeroU32,
@@ -36,7 +38,7 @@ use crate::{
     },
     output::Output,
     task::{ReadCellError, Task, TaskType},
-    task_statistics::TaskStatisticsApi,
+    task_statistics::{InvalidationCause, TaskStatisticsApi},
 };
 
 fn prehash_task_type(task_type: CachedTaskType) -> PreHashed<CachedTaskType> {
@@ -409,6 +411,14 @@ impl Backend for MemoryBackend {
     }
 
     fn invalidate_task(&self, task: TaskId, turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>) {
+        self.task_statistics().map(|stats| {
+            stats.record_invalidation(
+                task,
+                self.with_task(task, |task| task.get_function_id()),
+                InvalidationCause::Invalidator,
+                None,
+            )
+        });
         self.with_task(task, |task| task.invalidate(self, turbo_tasks));
     }
 
@@ -436,10 +446,64 @@ impl Backend for MemoryBackend {
         }
     }
 
+    fn invalidate_all_tasks(&self, turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>) {
+        self.with_all_cached_tasks(|task| {
+            self.invalidate_task(task, turbo_tasks);
+        });
+    }
+
+    fn invalidate_function_tasks(
+        &self,
+        function_id: FunctionId,
+        turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>,
+    ) {
+        // Collect the tasks first to avoid holding locks on the task cache during invalidation
+        let tasks = self
+            .task_cache
+            .iter()
+            .chain(self.transient_task_cache.iter())
+            .filter(|entry| entry.key().try_get_function_id() == Some(function_id))
+            .map(|entry| *entry.value())
+            .collect::<Vec<_>>();
+        for task in tasks {
+            self.invalidate_task(task, turbo_tasks);
+        }
+    }
+
     fn get_task_description(&self, task: TaskId) -> String {
         self.with_task(task, |task| task.get_description())
     }
 
+    fn dump_graph(&self) -> String {
+        // Only tasks from the task caches are listed. A task is stored before
+        // its id is added to a cache, so reading it can't race with its
+        // insertion. Root and once tasks aren't cached, but the tasks they call
+        // are.
+        let mut ids = Vec::new();
+        self.with_all_cached_tasks(|id| ids.push(id));
+        ids.sort();
+        let tasks = ids
+            .iter()
+            .map(|&id| (id, self.task(id)))
+            .collect::<HashMap<_, _>>();
+
+        let mut out = String::new();
+        for id in ids {
+            let task = tasks[&id];
+            let (state, children) = task.dump_state();
+            writeln!(out, "{} ({state})", task.get_description()).unwrap();
+            for child in children {
+                let Some(child) = tasks.get(&child) else {
+                    continue;
+                };
+                if child.is_pending() {
+                    writeln!(out, "  waiting for {}", child.get_description()).unwrap();
+                }
+            }
+        }
+        out
+    }
+
     type TaskState = TaskState;
     fn new_task_state(&self, _task: TaskId) -> Self::TaskState {
         TaskState {
@@ -488,6 +552,11 @@ impl Backend for MemoryBackend {
             // SAFETY: 1 is not zero
             unsafe { NonZeroU32::new_unchecked(1) }
         };
+        self.task_statistics().map(|stats| {
+            if let Some(function_id) = self.with_task(task_id, |task| task.get_function_id()) {
+                stats.record_execution_duration(function_id, duration);
+            }
+        });
         let (reexecute, once_task) = self.with_task(task_id, |task| {
             (
                 task.execution_completed(
@@ -663,11 +732,22 @@ impl Backend for MemoryBackend {
         content: CellContent,
         turbo_tasks: &dyn TurboTasksBackendApi<MemoryBackend>,
     ) {
-        self.with_task(task, |task| {
+        let notified_tasks = self.with_task(task, |task| {
             task.access_cell_for_write(index, |cell, clean| {
                 cell.assign(content, clean, turbo_tasks)
             })
-        })
+        });
+        self.task_statistics().map(|stats| {
+            let function_id = self.with_task(task, |task| task.get_function_id());
+            for &dependent_task in &notified_tasks {
+                stats.record_invalidation(
+                    dependent_task,
+                    self.with_task(dependent_task, |task| task.get_function_id()),
+                    InvalidationCause::CellChange { task, cell: index },
+                    function_id,
+                );
+            }
+        });
     }
 
     /// SAFETY: Must only called once with the same id
@@ -824,6 +904,7 @@ impl Backend for MemoryBackend {
 
     fn dispose_root_task(&self, task: TaskId, turbo_tasks: &dyn TurboTasksBackendApi<Self>) {
         Task::unset_root(task, self, turbo_tasks);
+        self.task_statistics().map(|stats| stats.forget_task(task));
     }
 }
 
@@ -21,8 +21,8 @@ use turbo_prehash::PreHashed;
 use turbo_tasks::{
     backend::{CachedTaskType, CellContent, TaskCollectiblesMap, TaskExecutionSpec},
     event::{Event, EventListener},
-    get_invalidator, registry, CellId, Invalidator, RawVc, ReadConsistency, TaskId, TaskIdSet,
-    TraitTypeId, TurboTasksBackendApi, TurboTasksBackendApiExt, ValueTypeId,
+    get_invalidator, registry, CellId, FunctionId, Invalidator, RawVc, ReadConsistency, TaskId,
+    TaskIdSet, TraitTypeId, TurboTasksBackendApi, TurboTasksBackendApiExt, ValueTypeId,
 };
 
 use crate::{
@@ -590,6 +590,14 @@ impl Task {
         }
     }
 
+    pub(crate) fn get_function_id(&self) -> Option<FunctionId> {
+        if let TaskType::Persistent { ty, .. } | TaskType::Transient { ty, .. } = &self.ty {
+            ty.try_get_function_id()
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn get_description(&self) -> String {
         Self::format_description(&TaskTypeForDescription::from(&self.ty), self.id)
     }
@@ -1504,6 +1512,19 @@ impl Task {
         }
     }
 
+    /// Returns the state of the task and its children, for
+    /// [turbo_tasks::backend::Backend::dump_graph].
+    pub(crate) fn dump_state(&self) -> (&'static str, Vec<TaskId>) {
+        match self.state() {
+            TaskMetaStateReadGuard::Full(state) => (
+                Self::state_string(&state),
+                state.state_type.children().collect(),
+            ),
+            TaskMetaStateReadGuard::Partial(_) => ("partial", Vec::new()),
+            TaskMetaStateReadGuard::Unloaded => ("unloaded", Vec::new()),
+        }
+    }
+
     fn state_string(state: &TaskState) -> &'static str {
         match state.state_type {
             Scheduled { .. } => "scheduled",
@@ -1834,6 +1855,10 @@ impl Task {
         }
         // Task is now dirty, so we can safely unload it
 
+        backend
+            .task_statistics()
+            .map(|stats| stats.forget_task(self.id));
+
         let mut state = full_state.into_inner();
         let old_state = replace(
             &mut *state,
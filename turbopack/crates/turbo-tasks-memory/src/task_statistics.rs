@@ -1,12 +1,14 @@
 use std::{
+    collections::HashMap,
     hash::BuildHasherDefault,
     sync::{Arc, OnceLock},
+    time::Duration,
 };
 
 use dashmap::DashMap;
 use rustc_hash::FxHasher;
 use serde::{ser::SerializeMap, Serialize, Serializer};
-use turbo_tasks::{registry, FunctionId};
+use turbo_tasks::{registry, CellId, FunctionId, TaskId};
 
 /// An API for optionally enabling, updating, and reading aggregated statistics.
 #[derive(Default)]
@@ -19,6 +21,7 @@ impl TaskStatisticsApi {
         self.inner.get_or_init(|| {
             Arc::new(TaskStatistics {
                 inner: DashMap::with_hasher(Default::default()),
+                last_invalidation_causes: DashMap::with_hasher(Default::default()),
             })
         })
     }
@@ -44,6 +47,7 @@ impl TaskStatisticsApi {
 /// [`serde::Serialize`].
 pub struct TaskStatistics {
     inner: DashMap<FunctionId, TaskFunctionStatistics, BuildHasherDefault<FxHasher>>,
+    last_invalidation_causes: DashMap<TaskId, InvalidationCause, BuildHasherDefault<FxHasher>>,
 }
 
 impl TaskStatistics {
@@ -55,6 +59,84 @@ impl TaskStatistics {
         self.with_task_type_statistics(function_id, |stats| stats.cache_miss += 1)
     }
 
+    /// Returns the fraction of calls to the given function that were served
+    /// from the cache, or `None` if the function was never called.
+    ///
+    /// Functions with a consistently low ratio rarely benefit from caching and
+    /// are candidates for redesign.
+    pub fn cache_hit_ratio(&self, function_id: FunctionId) -> Option<f64> {
+        self.inner
+            .get(&function_id)
+            .and_then(|stats| stats.cache_hit_ratio())
+    }
+
+    pub(crate) fn record_execution_duration(&self, function_id: FunctionId, duration: Duration) {
+        self.with_task_type_statistics(function_id, |stats| stats.durations.push(duration))
+    }
+
+    /// Returns the median and 95th percentile of the most recent execution
+    /// durations of the given function, or `None` if it was never executed.
+    ///
+    /// Unlike an average, this distinguishes a function that is uniformly slow
+    /// from one with a few slow outliers.
+    pub fn duration_percentiles(&self, function_id: FunctionId) -> Option<DurationPercentiles> {
+        self.inner
+            .get(&function_id)
+            .and_then(|stats| stats.durations.percentiles())
+    }
+
+    pub(crate) fn record_invalidation(
+        &self,
+        task: TaskId,
+        function_id: Option<FunctionId>,
+        cause: InvalidationCause,
+        source_function_id: Option<FunctionId>,
+    ) {
+        self.last_invalidation_causes.insert(task, cause);
+        if let Some(function_id) = function_id {
+            let source = match cause {
+                InvalidationCause::CellChange { .. } => source_function_id
+                    .map_or(InvalidationSource::OtherTask, InvalidationSource::Function),
+                InvalidationCause::Invalidator => InvalidationSource::Invalidator,
+            };
+            self.with_task_type_statistics(function_id, |stats| {
+                *stats.invalidation_sources.entry(source).or_default() += 1
+            })
+        }
+    }
+
+    /// Returns why the given task was invalidated most recently, or `None` if
+    /// it was never invalidated.
+    pub fn last_invalidation_cause(&self, task: TaskId) -> Option<InvalidationCause> {
+        self.last_invalidation_causes
+            .get(&task)
+            .map(|cause| *cause.value())
+    }
+
+    /// Drops the recorded invalidation cause of a task whose state is gone,
+    /// e.g. because it was unloaded by garbage collection or disposed.
+    pub(crate) fn forget_task(&self, task: TaskId) {
+        self.last_invalidation_causes.remove(&task);
+    }
+
+    /// Returns what invalidated tasks of the given function, ordered from the
+    /// most to the least common source.
+    ///
+    /// Useful to find the source of over-invalidation when a function
+    /// re-executes more often than expected.
+    pub fn invalidation_sources(&self, function_id: FunctionId) -> Vec<(InvalidationSource, u32)> {
+        let Some(stats) = self.inner.get(&function_id) else {
+            return Vec::new();
+        };
+        let mut sources: Vec<_> = stats
+            .invalidation_sources
+            .iter()
+            .map(|(source, count)| (*source, *count))
+            .collect();
+        sources.sort_by(|(_, a), (_, b)| b.cmp(a));
+        sources
+    }
+
     fn with_task_type_statistics(
         &self,
         task_function_id: FunctionId,
@@ -69,6 +151,113 @@ impl TaskStatistics {
 struct TaskFunctionStatistics {
     cache_hit: u32,
     cache_miss: u32,
+    #[serde(skip_serializing_if = "DurationSamples::is_empty")]
+    durations: DurationSamples,
+    #[serde(skip)]
+    invalidation_sources: HashMap<InvalidationSource, u32>,
+}
+
+/// Why a task was invalidated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidationCause {
+    /// A cell the task depends on was updated with different content.
+    CellChange { task: TaskId, cell: CellId },
+    /// The task was invalidated directly through its
+    /// [`Invalidator`](turbo_tasks::Invalidator), e.g. by a file watcher or by
+    /// setting a [`State`](turbo_tasks::State).
+    Invalidator,
+}
+
+/// What invalidated tasks of a function, aggregated over all tasks of that
+/// function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidationSource {
+    /// A cell of a task of the given function changed.
+    Function(FunctionId),
+    /// A cell of a task that isn't a function call (e.g. a root task) changed.
+    OtherTask,
+    /// The task was invalidated directly through its
+    /// [`Invalidator`](turbo_tasks::Invalidator).
+    Invalidator,
+}
+
+impl TaskFunctionStatistics {
+    fn cache_hit_ratio(&self) -> Option<f64> {
+        let total = self.cache_hit + self.cache_miss;
+        if total == 0 {
+            return None;
+        }
+        Some(self.cache_hit as f64 / total as f64)
+    }
+}
+
+/// Percentiles over the recorded execution durations of a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationPercentiles {
+    pub p50: Duration,
+    pub p95: Duration,
+}
+
+/// The maximum number of execution durations kept per function. Older
+/// samples are overwritten once this is reached.
+const MAX_DURATION_SAMPLES: usize = 1024;
+
+/// A ring buffer of the most recent execution durations.
+#[derive(Default)]
+struct DurationSamples {
+    samples: Vec<Duration>,
+    next: usize,
+}
+
+impl DurationSamples {
+    fn push(&mut self, duration: Duration) {
+        if self.samples.len() < MAX_DURATION_SAMPLES {
+            self.samples.push(duration);
+        } else {
+            self.samples[self.next] = duration;
+            self.next = (self.next + 1) % MAX_DURATION_SAMPLES;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    fn percentiles(&self) -> Option<DurationPercentiles> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        Some(DurationPercentiles {
+            p50: nearest_rank(&sorted, 50),
+            p95: nearest_rank(&sorted, 95),
+        })
+    }
+}
+
+/// Nearest-rank percentile of a non-empty sorted slice.
+fn nearest_rank(sorted: &[Duration], percentile: usize) -> Duration {
+    let rank = (percentile * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+/// Serializes the percentiles in microseconds, e.g. `{ "p50_us": 120, "p95_us":
+/// 900 }`.
+impl Serialize for DurationSamples {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let percentiles = self.percentiles().unwrap_or(DurationPercentiles {
+            p50: Duration::ZERO,
+            p95: Duration::ZERO,
+        });
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("p50_us", &(percentiles.p50.as_micros() as u64))?;
+        map.serialize_entry("p95_us", &(percentiles.p95.as_micros() as u64))?;
+        map.end()
+    }
 }
 
 impl Serialize for TaskStatistics {
@@ -84,3 +273,115 @@ impl Serialize for TaskStatistics {
         map.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+    use turbo_tasks::TaskId;
+
+    use super::{
+        DurationPercentiles, DurationSamples, InvalidationCause, TaskFunctionStatistics,
+        TaskStatisticsApi, MAX_DURATION_SAMPLES,
+    };
+
+    fn millis(values: impl IntoIterator<Item = u64>) -> DurationSamples {
+        let mut samples = DurationSamples::default();
+        for value in values {
+            samples.push(Duration::from_millis(value));
+        }
+        samples
+    }
+
+    #[test]
+    fn percentiles_of_uniform_distribution() {
+        assert_eq!(
+            millis(1..=100).percentiles(),
+            Some(DurationPercentiles {
+                p50: Duration::from_millis(50),
+                p95: Duration::from_millis(95),
+            })
+        );
+    }
+
+    #[test]
+    fn percentiles_with_outlier() {
+        let samples = millis((0..19).map(|_| 1).chain([1000]));
+        assert_eq!(
+            samples.percentiles(),
+            Some(DurationPercentiles {
+                p50: Duration::from_millis(1),
+                p95: Duration::from_millis(1),
+            })
+        );
+        let samples = millis((0..9).map(|_| 1).chain([1000]));
+        assert_eq!(
+            samples.percentiles(),
+            Some(DurationPercentiles {
+                p50: Duration::from_millis(1),
+                p95: Duration::from_millis(1000),
+            })
+        );
+    }
+
+    #[test]
+    fn percentiles_of_empty_samples() {
+        assert_eq!(DurationSamples::default().percentiles(), None);
+    }
+
+    #[test]
+    fn samples_are_capped() {
+        let mut samples = millis((0..MAX_DURATION_SAMPLES).map(|_| 1000));
+        for _ in 0..MAX_DURATION_SAMPLES {
+            samples.push(Duration::from_millis(1));
+        }
+        assert_eq!(samples.samples.len(), MAX_DURATION_SAMPLES);
+        assert_eq!(samples.percentiles().unwrap().p95, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn percentiles_are_serialized() {
+        let stats = TaskFunctionStatistics {
+            cache_miss: 20,
+            durations: millis((0..19).map(|_| 1).chain([1000])),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&stats).unwrap(),
+            json!({
+                "cache_hit": 0,
+                "cache_miss": 20,
+                "durations": { "p50_us": 1000, "p95_us": 1000 },
+            })
+        );
+        let samples = millis((0..9).map(|_| 1).chain([1000]));
+        assert_eq!(
+            serde_json::to_value(&samples).unwrap(),
+            json!({ "p50_us": 1000, "p95_us": 1000000 })
+        );
+
+        assert_eq!(
+            serde_json::to_value(TaskFunctionStatistics::default()).unwrap(),
+            json!({ "cache_hit": 0, "cache_miss": 0 })
+        );
+    }
+
+    #[test]
+    fn forgotten_tasks_drop_their_invalidation_cause() {
+        let api = TaskStatisticsApi::default();
+        let stats = api.enable();
+        // SAFETY: 1 and 2 are not zero
+        let (first, second) = unsafe { (TaskId::new_unchecked(1), TaskId::new_unchecked(2)) };
+        stats.record_invalidation(first, None, InvalidationCause::Invalidator, None);
+        stats.record_invalidation(second, None, InvalidationCause::Invalidator, None);
+
+        stats.forget_task(first);
+        assert_eq!(stats.last_invalidation_cause(first), None);
+        assert_eq!(
+            stats.last_invalidation_cause(second),
+            Some(InvalidationCause::Invalidator)
+        );
+        assert_eq!(stats.last_invalidation_causes.len(), 1);
+    }
+}
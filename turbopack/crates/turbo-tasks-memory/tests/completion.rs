@@ -0,0 +1 @@
+../../turbo-tasks-testing/tests/completion.rs
\ No newline at end of file
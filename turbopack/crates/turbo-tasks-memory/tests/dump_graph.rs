@@ -0,0 +1,69 @@
+#![feature(arbitrary_self_types)]
+
+use anyhow::Result;
+use tokio::{
+    sync::Notify,
+    time::{timeout, Duration},
+};
+use turbo_tasks::{TransientInstance, TurboTasks, Vc};
+use turbo_tasks_memory::MemoryBackend;
+use turbo_tasks_testing::{register, Registration};
+
+static REGISTRATION: Registration = register!();
+
+#[tokio::test]
+async fn dump_graph_marks_in_progress_tasks() {
+    REGISTRATION.ensure_registered();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let tt_inner = tt.clone();
+    tt.run_once(async move {
+        assert_eq!(*double(21).await?, 42);
+
+        let notify = TransientInstance::new(Notify::new());
+        let out = outer(notify.clone());
+        timeout(Duration::from_millis(100), out.strongly_consistent())
+            .await
+            .expect_err("outer should wait for the blocked task");
+
+        let dump = tt_inner.dump_graph();
+        let lines = dump.lines().collect::<Vec<_>>();
+        let line = |name: &str| {
+            lines
+                .iter()
+                .position(|line| !line.starts_with(' ') && line.contains(name))
+                .unwrap_or_else(|| panic!("expected {name} in the dump:\n{dump}"))
+        };
+        assert!(lines[line("::double")].ends_with("(done)"), "{dump}");
+        assert!(lines[line("::blocked")].contains("(in progress"), "{dump}");
+        let outer_line = line("::outer");
+        assert!(lines[outer_line].contains("(in progress"), "{dump}");
+        assert!(
+            lines[outer_line + 1].starts_with("  waiting for")
+                && lines[outer_line + 1].contains("::blocked"),
+            "{dump}"
+        );
+
+        notify.notify_waiters();
+        out.strongly_consistent().await?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[turbo_tasks::function]
+fn double(value: u32) -> Vc<u32> {
+    Vc::cell(value * 2)
+}
+
+#[turbo_tasks::function]
+async fn outer(notify: TransientInstance<Notify>) -> Result<Vc<()>> {
+    blocked(notify).await?;
+    Ok(Vc::cell(()))
+}
+
+#[turbo_tasks::function]
+async fn blocked(notify: TransientInstance<Notify>) -> Vc<()> {
+    notify.notified().await;
+    Vc::cell(())
+}
@@ -0,0 +1,97 @@
+#![feature(arbitrary_self_types)]
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
+
+use turbo_tasks::{TurboTasks, Vc};
+use turbo_tasks_memory::MemoryBackend;
+use turbo_tasks_testing::{register, Registration};
+
+static REGISTRATION: Registration = register!();
+
+#[tokio::test]
+async fn test_invalidate_all_active() {
+    run_with_tt(|tt| async move {
+        let a = execution_id(1);
+        let b = execution_id(2);
+        let a_before = *a.strongly_consistent().await.unwrap();
+        let b_before = *b.strongly_consistent().await.unwrap();
+        // reading again is served from the cache
+        assert_eq!(*a.strongly_consistent().await.unwrap(), a_before);
+
+        tt.invalidate_all();
+
+        assert_ne!(*a.strongly_consistent().await.unwrap(), a_before);
+        assert_ne!(*b.strongly_consistent().await.unwrap(), b_before);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_invalidate_all_inactive() {
+    REGISTRATION.ensure_registered();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let read = || async { anyhow::Ok(*execution_id(1).strongly_consistent().await?) };
+    let before = tt.run_once(read()).await.unwrap();
+    assert_eq!(tt.run_once(read()).await.unwrap(), before);
+
+    // the task is no longer active, so it's only recomputed when it's read again
+    tt.invalidate_all();
+
+    assert_ne!(tt.run_once(read()).await.unwrap(), before);
+}
+
+#[tokio::test]
+async fn test_invalidate_function() {
+    run_with_tt(|tt| async move {
+        let invalidated = execution_id(1);
+        let unrelated = unrelated_execution_id(1);
+        let invalidated_before = *invalidated.strongly_consistent().await.unwrap();
+        let unrelated_before = *unrelated.strongly_consistent().await.unwrap();
+
+        tt.invalidate_function(&EXECUTION_ID_FUNCTION);
+
+        assert_ne!(
+            *invalidated.strongly_consistent().await.unwrap(),
+            invalidated_before
+        );
+        assert_eq!(
+            *unrelated.strongly_consistent().await.unwrap(),
+            unrelated_before
+        );
+    })
+    .await;
+}
+
+static NEXT_EXECUTION_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Returns a value that is unique for every execution of this function.
+#[turbo_tasks::function]
+fn execution_id(_key: u32) -> Vc<u32> {
+    Vc::cell(NEXT_EXECUTION_ID.fetch_add(1, Ordering::SeqCst))
+}
+
+#[turbo_tasks::function]
+fn unrelated_execution_id(_key: u32) -> Vc<u32> {
+    Vc::cell(NEXT_EXECUTION_ID.fetch_add(1, Ordering::SeqCst))
+}
+
+async fn run_with_tt<Fut>(func: impl FnOnce(Arc<TurboTasks<MemoryBackend>>) -> Fut)
+where
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    REGISTRATION.ensure_registered();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let fut = func(Arc::clone(&tt));
+    tt.run_once(async move {
+        fut.await;
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
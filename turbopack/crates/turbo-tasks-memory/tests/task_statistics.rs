@@ -9,8 +9,8 @@ use anyhow::Result;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde_json::json;
-use turbo_tasks::{TurboTasks, Vc};
-use turbo_tasks_memory::MemoryBackend;
+use turbo_tasks::{RawVc, State, TurboTasks, Vc};
+use turbo_tasks_memory::{InvalidationCause, InvalidationSource, MemoryBackend};
 use turbo_tasks_testing::{register, Registration};
 
 static REGISTRATION: Registration = register!();
@@ -184,6 +184,131 @@ async fn test_no_execution() {
     .await;
 }
 
+#[tokio::test]
+async fn test_cache_hit_ratio() {
+    run_with_tt(|tt| async move {
+        // equal inputs are served from the cache after the first call
+        for _ in 0..10 {
+            double(1).await.unwrap();
+        }
+        // varying inputs never hit the cache
+        for i in 0..10 {
+            wrap(i).await.unwrap();
+        }
+        let stats = tt.backend().task_statistics().get().unwrap();
+        assert_eq!(stats.cache_hit_ratio(*DOUBLE_FUNCTION_ID), Some(0.9));
+        assert_eq!(stats.cache_hit_ratio(*WRAP_FUNCTION_ID), Some(0.0));
+        assert_eq!(stats.cache_hit_ratio(*DOUBLE_VC_FUNCTION_ID), None);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_duration_percentiles() {
+    run_with_tt(|tt| async move {
+        // the percentiles themselves are tested with fixed samples in the unit
+        // tests, here we only check that executions are recorded
+        for i in 0..10 {
+            double(i).await.unwrap();
+        }
+        let stats = tt.backend().task_statistics().get().unwrap();
+        let percentiles = stats.duration_percentiles(*DOUBLE_FUNCTION_ID).unwrap();
+        assert!(percentiles.p50 <= percentiles.p95);
+        assert_eq!(stats.duration_percentiles(*WRAP_FUNCTION_ID), None);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_invalidation_cause() {
+    run_with_tt(|tt| async move {
+        let input = ChangingInput {
+            state: State::new(1),
+        }
+        .cell();
+        let read = read_input(input);
+        let doubled = double_input(input);
+        assert_eq!(*doubled.strongly_consistent().await.unwrap(), 2);
+
+        input.await.unwrap().state.set(2);
+        assert_eq!(*doubled.strongly_consistent().await.unwrap(), 4);
+
+        let RawVc::TaskCell(read_task, read_cell) = Vc::into_raw(read.resolve().await.unwrap())
+        else {
+            panic!("expected a resolved cell");
+        };
+        let doubled_task = Vc::into_raw(doubled).get_task_id();
+        let stats = tt.backend().task_statistics().get().unwrap();
+        // the state change invalidates the reading task directly...
+        assert_eq!(
+            stats.last_invalidation_cause(read_task),
+            Some(InvalidationCause::Invalidator)
+        );
+        // ...which then updates its cell and invalidates the dependent task
+        assert_eq!(
+            stats.last_invalidation_cause(doubled_task),
+            Some(InvalidationCause::CellChange {
+                task: read_task,
+                cell: read_cell,
+            })
+        );
+        assert_eq!(
+            stats.invalidation_sources(*READ_INPUT_FUNCTION_ID),
+            vec![(InvalidationSource::Invalidator, 1)]
+        );
+        assert_eq!(
+            stats.invalidation_sources(*DOUBLE_INPUT_FUNCTION_ID),
+            vec![(InvalidationSource::Function(*READ_INPUT_FUNCTION_ID), 1)]
+        );
+        assert_eq!(
+            stats.last_invalidation_cause(Vc::into_raw(double(1)).get_task_id()),
+            None
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_invalidate_all_cause() {
+    run_with_tt(|tt| async move {
+        double(1).strongly_consistent().await.unwrap();
+
+        tt.invalidate_all();
+
+        let stats = tt.backend().task_statistics().get().unwrap();
+        assert_eq!(
+            stats.last_invalidation_cause(Vc::into_raw(double(1)).get_task_id()),
+            Some(InvalidationCause::Invalidator)
+        );
+        assert_eq!(
+            stats.invalidation_sources(*DOUBLE_FUNCTION_ID),
+            vec![(InvalidationSource::Invalidator, 1)]
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_invalidate_function_cause() {
+    run_with_tt(|tt| async move {
+        double(1).strongly_consistent().await.unwrap();
+        wrap(1).strongly_consistent().await.unwrap();
+
+        tt.invalidate_function(&DOUBLE_FUNCTION);
+
+        let stats = tt.backend().task_statistics().get().unwrap();
+        assert_eq!(
+            stats.last_invalidation_cause(Vc::into_raw(double(1)).get_task_id()),
+            Some(InvalidationCause::Invalidator)
+        );
+        assert_eq!(
+            stats.last_invalidation_cause(Vc::into_raw(wrap(1)).get_task_id()),
+            None
+        );
+    })
+    .await;
+}
+
 // Internally, this function uses `CachedTaskType::Native`.
 #[turbo_tasks::function]
 fn double(val: u64) -> Vc<u64> {
@@ -230,6 +355,21 @@ impl Doublable for WrappedU64 {
     }
 }
 
+#[turbo_tasks::value]
+struct ChangingInput {
+    state: State<u32>,
+}
+
+#[turbo_tasks::function]
+async fn read_input(input: Vc<ChangingInput>) -> Result<Vc<u32>> {
+    Ok(Vc::cell(*input.await?.state.get()))
+}
+
+#[turbo_tasks::function]
+async fn double_input(input: Vc<ChangingInput>) -> Result<Vc<u32>> {
+    Ok(Vc::cell(*read_input(input).await? * 2))
+}
+
 #[turbo_tasks::function]
 async fn fail(val: u64) -> Result<Vc<()>> {
     anyhow::bail!("failed using {val}");
@@ -252,7 +392,22 @@ where
 }
 
 fn stats_json(tt: &TurboTasks<MemoryBackend>) -> serde_json::Value {
-    remove_hashes(serde_json::to_value(tt.backend().task_statistics().get()).unwrap())
+    remove_durations(remove_hashes(
+        serde_json::to_value(tt.backend().task_statistics().get()).unwrap(),
+    ))
+}
+
+// Execution durations vary between runs. Their serialization is covered by the
+// unit tests.
+fn remove_durations(mut json: serde_json::Value) -> serde_json::Value {
+    if let serde_json::Value::Object(map) = &mut json {
+        for stats in map.values_mut() {
+            if let serde_json::Value::Object(stats) = stats {
+                stats.remove("durations");
+            }
+        }
+    }
+    json
 }
 
 // Global task identifiers can contain a hash of the crate and dependencies.
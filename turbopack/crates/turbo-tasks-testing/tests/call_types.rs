@@ -51,6 +51,39 @@ async fn async_fn_vc_arg(n: Vc<u32>) -> Result<Vc<u32>> {
     Ok(Vc::cell(*n.await?))
 }
 
+#[tokio::test]
+async fn value_args() {
+    run(&REGISTRATION, || async {
+        let a = fn_value_arg(turbo_tasks::Value::new(Kind::A));
+        assert_eq!(*a.await?, 1);
+        let b = fn_value_arg(Kind::B.into());
+        assert_eq!(*b.await?, 2);
+
+        // equal values share the cache entry, different ones don't
+        let a_again = fn_value_arg(Kind::A.into());
+        assert_eq!(a.resolve().await?, a_again.resolve().await?);
+        assert_ne!(a.resolve().await?, b.resolve().await?);
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Clone, Copy, Hash)]
+enum Kind {
+    A,
+    B,
+}
+
+#[turbo_tasks::function]
+fn fn_value_arg(kind: turbo_tasks::Value<Kind>) -> Vc<u32> {
+    Vc::cell(match *kind {
+        Kind::A => 1,
+        Kind::B => 2,
+    })
+}
+
 #[tokio::test]
 async fn methods() {
     run(&REGISTRATION, || async {
@@ -191,3 +224,92 @@ impl ValueTrait for Value {
         Ok(Vc::cell(self.0))
     }
 }
+
+#[tokio::test]
+async fn supertrait_methods() {
+    run(&REGISTRATION, || async {
+        let sub_trait_value: Vc<Box<dyn SubTrait>> = Vc::upcast(Value(43).cell());
+        assert_eq!(*sub_trait_value.sub_trait_method().await?, 44);
+        assert_eq!(*sub_trait_value.trait_method().await?, 43);
+        assert_eq!(*sub_trait_value.async_trait_method().await?, 43);
+        assert_eq!(*sub_trait_value.default_trait_method().await?, 42);
+
+        let sub_trait_value = wrap_sub_trait_value(sub_trait_value);
+        assert_eq!(*sub_trait_value.sub_trait_method().await?, 44);
+        assert_eq!(*sub_trait_value.trait_method().await?, 43);
+        assert_eq!(*sub_trait_value.default_async_trait_method().await?, 42);
+
+        let trait_value: Vc<Box<dyn ValueTrait>> = Vc::upcast(sub_trait_value);
+        assert_eq!(*trait_value.trait_method().await?, 43);
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[turbo_tasks::function]
+fn wrap_sub_trait_value(v: Vc<Box<dyn SubTrait>>) -> Vc<Box<dyn SubTrait>> {
+    v
+}
+
+#[turbo_tasks::value_trait]
+trait SubTrait: ValueTrait {
+    fn sub_trait_method(&self) -> Vc<u32>;
+}
+
+#[turbo_tasks::value_impl]
+impl SubTrait for Value {
+    #[turbo_tasks::function]
+    fn sub_trait_method(&self) -> Vc<u32> {
+        Vc::cell(self.0 + 1)
+    }
+}
+
+#[tokio::test]
+async fn sidecast() {
+    run(&REGISTRATION, || async {
+        let trait_value: Vc<Box<dyn ValueTrait>> = Vc::upcast(Value(43).cell());
+        let sub_trait_value = Vc::resolve_sidecast::<Box<dyn SubTrait>>(trait_value).await?;
+        assert_eq!(*sub_trait_value.sub_trait_method().await?, 44);
+
+        let trait_value: Vc<Box<dyn ValueTrait>> = Vc::upcast(OtherValue(43).cell());
+        assert!(Vc::try_resolve_sidecast::<Box<dyn SubTrait>>(trait_value)
+            .await?
+            .is_none());
+        let error = Vc::resolve_sidecast::<Box<dyn SubTrait>>(trait_value)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("OtherValue"), "{error}");
+        assert!(error.contains("SubTrait"), "{error}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[turbo_tasks::value]
+struct OtherValue(u32);
+
+#[turbo_tasks::value_impl]
+impl ValueTrait for OtherValue {
+    #[turbo_tasks::function]
+    fn static_trait_method() -> Vc<u32> {
+        Vc::cell(42)
+    }
+
+    #[turbo_tasks::function]
+    async fn async_static_trait_method() -> Result<Vc<u32>> {
+        Ok(Vc::cell(42))
+    }
+
+    #[turbo_tasks::function]
+    fn trait_method(&self) -> Vc<u32> {
+        Vc::cell(self.0)
+    }
+
+    #[turbo_tasks::function]
+    async fn async_trait_method(&self) -> Result<Vc<u32>> {
+        Ok(Vc::cell(self.0))
+    }
+}
@@ -0,0 +1,35 @@
+#![feature(arbitrary_self_types)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Result;
+use turbo_tasks::{Completion, Completions, Vc};
+use turbo_tasks_testing::{register, run, Registration};
+
+static REGISTRATION: Registration = register!();
+
+static EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[tokio::test]
+async fn unit_and_completion() {
+    run(&REGISTRATION, || async {
+        let done = Completions::all(vec![unit_task().into(), completion_task()]);
+        done.strongly_consistent().await?;
+        assert_eq!(EXECUTIONS.load(Ordering::SeqCst), 2);
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[turbo_tasks::function]
+fn unit_task() -> Vc<()> {
+    EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+    Vc::cell(())
+}
+
+#[turbo_tasks::function]
+async fn completion_task() -> Result<Vc<Completion>> {
+    EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+    Ok(Completion::new())
+}
@@ -455,6 +455,18 @@ pub trait Backend: Sync + Send {
     fn invalidate_tasks(&self, tasks: &[TaskId], turbo_tasks: &dyn TurboTasksBackendApi<Self>);
     fn invalidate_tasks_set(&self, tasks: &TaskIdSet, turbo_tasks: &dyn TurboTasksBackendApi<Self>);
 
+    /// Invalidates all cached tasks. Active tasks are scheduled for re-execution, inactive tasks
+    /// are recomputed when they are read the next time.
+    fn invalidate_all_tasks(&self, turbo_tasks: &dyn TurboTasksBackendApi<Self>);
+
+    /// Invalidates all cached tasks of the given function, e.g. to recompute all parsing tasks
+    /// after a change to the transform configuration.
+    fn invalidate_function_tasks(
+        &self,
+        function_id: FunctionId,
+        turbo_tasks: &dyn TurboTasksBackendApi<Self>,
+    );
+
     fn invalidate_serialization(
         &self,
         _task: TaskId,
@@ -464,6 +476,13 @@ pub trait Backend: Sync + Send {
 
     fn get_task_description(&self, task: TaskId) -> String;
 
+    /// Returns a human readable snapshot of the task graph: every task with
+    /// its state and the pending tasks it's waiting for. Can be attached to
+    /// bug reports about stuck builds.
+    fn dump_graph(&self) -> String {
+        "this backend doesn't support dumping the task graph\n".to_string()
+    }
+
     /// Task-local state that stored inside of [`TurboTasksBackendApi`]. Constructed with
     /// [`Self::new_task_state`].
     ///
@@ -759,6 +778,7 @@ pub(crate) mod tests {
             CachedTaskType::ResolveTrait {
                 trait_type: *MOCKTRAIT_TRAIT_TYPE_ID,
                 method_name: "mock_method_task".into(),
+                // SAFETY: 1 is not zero
                 this: RawVc::TaskOutput(unsafe { TaskId::new_unchecked(1) }),
                 arg: Box::new(()),
             }
@@ -42,6 +42,20 @@ impl Completion {
     }
 }
 
+/// Converts the result of a function returning `Vc<()>` into a completion, so
+/// it can be merged with other completions, e.g. via [`Completions::all`].
+impl From<Vc<()>> for Vc<Completion> {
+    fn from(unit: Vc<()>) -> Self {
+        unit_completed(unit)
+    }
+}
+
+#[turbo_tasks::function]
+async fn unit_completed(unit: Vc<()>) -> anyhow::Result<Vc<Completion>> {
+    unit.await?;
+    Ok(Completion::new())
+}
+
 #[turbo_tasks::value(transparent)]
 pub struct Completions(Vec<Vc<Completion>>);
 
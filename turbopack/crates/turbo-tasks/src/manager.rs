@@ -45,8 +45,8 @@ use crate::{
     trait_helpers::get_trait_method,
     util::StaticOrArc,
     vc::ReadVcFuture,
-    Completion, FunctionMeta, InvalidationReason, InvalidationReasonSet, SharedReference, TaskId,
-    TaskIdSet, ValueTypeId, Vc, VcRead, VcValueTrait, VcValueType,
+    Completion, FunctionMeta, InvalidationReason, InvalidationReasonSet, NativeFunction,
+    SharedReference, TaskId, TaskIdSet, ValueTypeId, Vc, VcRead, VcValueTrait, VcValueType,
 };
 
 pub trait TurboTasksCallApi: Sync + Send {
@@ -913,6 +913,25 @@ impl<B: Backend + 'static> TurboTasks<B> {
             .await;
     }
 
+    /// Invalidates every cached task so that the next read recomputes it, without tearing down
+    /// the instance. This is useful to force a clean rebuild, e.g. after a change to
+    /// configuration that isn't tracked by any task.
+    ///
+    /// Active tasks are scheduled for re-execution immediately, inactive tasks are recomputed
+    /// when they are read the next time.
+    #[instrument(level = Level::INFO, skip_all, name = "invalidate_all")]
+    pub fn invalidate_all(&self) {
+        self.backend.invalidate_all_tasks(self);
+    }
+
+    /// Invalidates all cached tasks of the given function. This is far cheaper than
+    /// [`TurboTasks::invalidate_all`] when only the behavior of a single function changed.
+    #[instrument(level = Level::INFO, skip_all, name = "invalidate_function")]
+    pub fn invalidate_function(&self, function: &'static NativeFunction) {
+        self.backend
+            .invalidate_function_tasks(registry::get_function_id(function), self);
+    }
+
     pub fn get_in_progress_count(&self) -> usize {
         self.currently_scheduled_tasks.load(Ordering::Acquire)
     }
@@ -1151,6 +1170,12 @@ impl<B: Backend + 'static> TurboTasks<B> {
     pub fn backend(&self) -> &B {
         &self.backend
     }
+
+    /// Returns a snapshot of the task graph for debugging, see
+    /// [Backend::dump_graph]. This can be called while tasks are executing.
+    pub fn dump_graph(&self) -> String {
+        self.backend.dump_graph()
+    }
 }
 
 impl<B: Backend + 'static> TurboTasksCallApi for TurboTasks<B> {
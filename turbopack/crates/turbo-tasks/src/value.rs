@@ -34,6 +34,12 @@ impl<T> Deref for Value<T> {
     }
 }
 
+impl<T> From<T> for Value<T> {
+    fn from(value: T) -> Self {
+        Value::new(value)
+    }
+}
+
 impl<T: Copy> Copy for Value<T> {}
 
 impl<T: Default> Default for Value<T> {
@@ -18,7 +18,7 @@ use crate::{
     registry::{register_trait_type, register_value_type},
     task::shared_reference::TypedSharedReference,
     vc::VcCellMode,
-    RawVc, VcValueType,
+    RawVc, VcValueTrait, VcValueType,
 };
 
 type MagicSerializationFn = fn(&dyn MagicAny) -> &dyn erased_serde::Serialize;
@@ -206,6 +206,21 @@ impl ValueType {
         self.traits.iter().copied()
     }
 
+    /// Returns true if this value type implements the value trait `T`, e.g.
+    /// `value_type.implements::<Box<dyn ValueToString>>()`.
+    pub fn implements<T: VcValueTrait + ?Sized>(&self) -> bool {
+        self.has_trait(&T::get_trait_type_id())
+    }
+
+    /// Iterates the trait methods this value type implements itself, as the
+    /// trait, the method name and the implementing function. Default methods
+    /// of the traits are not included.
+    pub fn trait_methods_iter(&self) -> impl Iterator<Item = (TraitTypeId, &str, FunctionId)> + '_ {
+        self.trait_methods
+            .iter()
+            .map(|((trait_type, name), function)| (*trait_type, &**name, *function))
+    }
+
     pub fn register(&'static self, global_name: &'static str) {
         register_value_type(global_name, self)
     }
@@ -301,3 +316,65 @@ impl TraitType {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as turbo_tasks, registry, Vc, VcValueType};
+
+    #[turbo_tasks::value]
+    struct MockValue(u32);
+
+    #[turbo_tasks::value_trait]
+    trait MockTraitA {
+        fn method_a(self: Vc<Self>) -> Vc<()>;
+    }
+
+    #[turbo_tasks::value_trait]
+    trait MockTraitB {
+        fn method_b(self: Vc<Self>) -> Vc<()>;
+    }
+
+    #[turbo_tasks::value_trait]
+    trait MockTraitC {
+        fn method_c(self: Vc<Self>) -> Vc<()>;
+    }
+
+    #[turbo_tasks::value_impl]
+    impl MockTraitA for MockValue {
+        #[turbo_tasks::function]
+        fn method_a(self: Vc<Self>) -> Vc<()> {
+            Vc::cell(())
+        }
+    }
+
+    #[turbo_tasks::value_impl]
+    impl MockTraitB for MockValue {
+        #[turbo_tasks::function]
+        fn method_b(self: Vc<Self>) -> Vc<()> {
+            Vc::cell(())
+        }
+    }
+
+    #[test]
+    fn test_trait_introspection() {
+        crate::register();
+        let value_type = registry::get_value_type(MockValue::get_value_type_id());
+
+        assert!(value_type.implements::<Box<dyn MockTraitA>>());
+        assert!(value_type.implements::<Box<dyn MockTraitB>>());
+        assert!(!value_type.implements::<Box<dyn MockTraitC>>());
+
+        let mut methods = value_type
+            .trait_methods_iter()
+            .map(|(trait_type, name, _)| (registry::get_trait(trait_type).name.clone(), name))
+            .collect::<Vec<_>>();
+        methods.sort();
+        assert_eq!(
+            methods,
+            vec![
+                ("MockTraitA".to_string(), "method_a"),
+                ("MockTraitB".to_string(), "method_b"),
+            ]
+        );
+    }
+}
@@ -12,7 +12,7 @@ use std::{
     ops::Deref,
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use auto_hash_map::AutoSet;
 use serde::{Deserialize, Serialize};
 
@@ -460,6 +460,30 @@ where
         }))
     }
 
+    /// Sidecasts the given `Vc<Box<dyn T>>` to a `Vc<Box<dyn K>>`, like
+    /// [`Vc::try_resolve_sidecast`]. This operation also resolves the `Vc`.
+    ///
+    /// Fails with an error naming the underlying value type and the trait `K`
+    /// if the value type does not implement `K`.
+    pub async fn resolve_sidecast<K>(vc: Self) -> Result<Vc<K>>
+    where
+        K: VcValueTrait + ?Sized + Send,
+    {
+        let vc = vc.resolve().await?;
+        if let Some(sidecast) = Self::try_resolve_sidecast::<K>(vc).await? {
+            return Ok(sidecast);
+        }
+        let trait_type = registry::get_trait(<K as VcValueTrait>::get_trait_type_id());
+        match vc.node {
+            RawVc::TaskCell(_, CellId { type_id, .. }) => bail!(
+                "{} doesn't implement {}",
+                registry::get_value_type(type_id),
+                trait_type
+            ),
+            _ => bail!("value doesn't implement {}", trait_type),
+        }
+    }
+
     /// Attempts to downcast the given `Vc<Box<dyn T>>` to a `Vc<K>`, where `K`
     /// is of the form `Box<dyn L>`, and `L` is a value trait.
     /// This operation also resolves the `Vc`.
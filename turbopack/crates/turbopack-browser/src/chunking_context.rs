@@ -5,8 +5,9 @@ use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
     chunk::{
         availability_info::AvailabilityInfo,
+        base_path_with_trailing_slash,
         chunk_group::{make_chunk_group, MakeChunkGroupResult},
-        module_id_strategies::{DevModuleIdStrategy, ModuleIdStrategy},
+        module_id_strategies::{DevModuleIdStrategy, GlobalModuleIdStrategy, ModuleIdStrategy},
         Chunk, ChunkGroupResult, ChunkItem, ChunkableModule, ChunkingContext,
         EntryChunkGroupResult, EvaluatableAssets, MinifyType, ModuleId,
     },
@@ -163,6 +164,55 @@ impl BrowserChunkingContext {
             },
         }
     }
+
+    /// Creates a chunking context builder with defaults for development:
+    /// readable module ids, hot module replacement and source maps, without
+    /// minification.
+    pub fn development(
+        context_path: Vc<FileSystemPath>,
+        output_root: Vc<FileSystemPath>,
+        client_root: Vc<FileSystemPath>,
+        chunk_root_path: Vc<FileSystemPath>,
+        asset_root_path: Vc<FileSystemPath>,
+        environment: Vc<Environment>,
+    ) -> BrowserChunkingContextBuilder {
+        Self::builder(
+            context_path,
+            output_root,
+            client_root,
+            chunk_root_path,
+            asset_root_path,
+            environment,
+            RuntimeType::Development,
+        )
+        .hot_module_replacement()
+    }
+
+    /// Creates a chunking context builder with defaults for production:
+    /// hashed module ids and minification, without hot module replacement or
+    /// source map references.
+    pub fn production(
+        context_path: Vc<FileSystemPath>,
+        output_root: Vc<FileSystemPath>,
+        client_root: Vc<FileSystemPath>,
+        chunk_root_path: Vc<FileSystemPath>,
+        asset_root_path: Vc<FileSystemPath>,
+        environment: Vc<Environment>,
+    ) -> BrowserChunkingContextBuilder {
+        Self::builder(
+            context_path,
+            output_root,
+            client_root,
+            chunk_root_path,
+            asset_root_path,
+            environment,
+            RuntimeType::Production,
+        )
+        .minify_type(MinifyType::Minify)
+        .module_id_strategy(Vc::upcast(GlobalModuleIdStrategy::hashed()))
+        .reference_chunk_source_maps(false)
+        .reference_css_chunk_source_maps(false)
+    }
 }
 
 impl BrowserChunkingContext {
@@ -290,14 +340,13 @@ impl ChunkingContext for BrowserChunkingContext {
             .strip_prefix(&format!("{}/", this.client_root.await?.path))
             .context("expected asset_path to contain client_root")?;
 
+        let asset_base_path = this.asset_base_path.await?;
+        let asset_base_path = asset_base_path.as_deref().unwrap_or("/");
+
         Ok(Vc::cell(
             format!(
                 "{}{}",
-                this.asset_base_path
-                    .await?
-                    .as_ref()
-                    .map(|s| s.as_str())
-                    .unwrap_or("/"),
+                base_path_with_trailing_slash(asset_base_path),
                 asset_path
             )
             .into(),
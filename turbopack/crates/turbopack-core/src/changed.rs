@@ -14,7 +14,14 @@ use crate::{
 async fn get_referenced_output_assets(
     parent: Vc<Box<dyn OutputAsset>>,
 ) -> Result<impl Iterator<Item = Vc<Box<dyn OutputAsset>>> + Send> {
-    Ok(parent.references().await?.clone_value().into_iter())
+    Ok(parent
+        .references()
+        .await?
+        .iter()
+        .chain(parent.auxiliary_assets().await?.iter())
+        .copied()
+        .collect::<Vec<_>>()
+        .into_iter())
 }
 
 pub async fn get_referenced_modules(
@@ -1,10 +1,14 @@
+use std::borrow::Cow;
+
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use turbo_tasks::{trace::TraceRawVcs, RcStr, TaskInput, Upcast, Value, Vc};
 use turbo_tasks_fs::FileSystemPath;
 use turbo_tasks_hash::DeterministicHash;
 
-use super::{availability_info::AvailabilityInfo, ChunkableModule, EvaluatableAssets};
+use super::{
+    availability_info::AvailabilityInfo, ChunkableModule, EntryChunksManifest, EvaluatableAssets,
+};
 use crate::{
     chunk::{ChunkItem, ModuleId},
     environment::Environment,
@@ -35,6 +39,16 @@ pub enum MinifyType {
     NoMinify,
 }
 
+/// Appends a trailing slash to a non-empty base path (e.g. a public path
+/// like `/assets`), so that chunk and asset paths can be appended to it.
+pub fn base_path_with_trailing_slash(base_path: &str) -> Cow<'_, str> {
+    if base_path.is_empty() || base_path.ends_with('/') {
+        Cow::Borrowed(base_path)
+    } else {
+        Cow::Owned(format!("{base_path}/"))
+    }
+}
+
 #[turbo_tasks::value(shared)]
 pub struct ChunkGroupResult {
     pub assets: Vc<OutputAssets>,
@@ -183,6 +197,16 @@ pub trait ChunkingContextExt {
     ) -> Vc<OutputAssets>
     where
         Self: Send;
+
+    /// Generates a JSON manifest at `path` that lists the chunks of each
+    /// named entry chunk group, relative to the output root.
+    fn entry_chunks_manifest(
+        self: Vc<Self>,
+        path: Vc<FileSystemPath>,
+        entries: Vec<(RcStr, Vc<OutputAssets>)>,
+    ) -> Vc<Box<dyn OutputAsset>>
+    where
+        Self: Send;
 }
 
 impl<T: ChunkingContext + Send + Upcast<Box<dyn ChunkingContext>>> ChunkingContextExt for T {
@@ -266,6 +290,14 @@ impl<T: ChunkingContext + Send + Upcast<Box<dyn ChunkingContext>>> ChunkingConte
     ) -> Vc<OutputAssets> {
         chunk_group_assets(Vc::upcast(self), module, availability_info)
     }
+
+    fn entry_chunks_manifest(
+        self: Vc<Self>,
+        path: Vc<FileSystemPath>,
+        entries: Vec<(RcStr, Vc<OutputAssets>)>,
+    ) -> Vc<Box<dyn OutputAsset>> {
+        Vc::upcast(EntryChunksManifest::new(path, self.output_root(), entries))
+    }
 }
 
 #[turbo_tasks::function]
@@ -314,3 +346,20 @@ async fn chunk_group_assets(
         .await?
         .assets)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::base_path_with_trailing_slash;
+
+    #[test]
+    fn base_path_trailing_slash() {
+        assert_eq!(base_path_with_trailing_slash(""), "");
+        assert_eq!(base_path_with_trailing_slash("/"), "/");
+        assert_eq!(base_path_with_trailing_slash("/assets"), "/assets/");
+        assert_eq!(base_path_with_trailing_slash("/assets/"), "/assets/");
+        assert_eq!(
+            base_path_with_trailing_slash("https://cdn.example.com"),
+            "https://cdn.example.com/"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use turbo_tasks::{RcStr, TryJoinIterExt, Vc};
+use turbo_tasks_fs::{File, FileSystemPath};
+
+use crate::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    output::{OutputAsset, OutputAssets},
+};
+
+/// A JSON manifest that maps entry names to the paths of their chunks,
+/// relative to the output root.
+///
+/// ```json
+/// { "main": ["chunks/main.js", "chunks/shared.js"] }
+/// ```
+///
+/// Entries are sorted by name. The chunks of an entry keep the order of their
+/// chunk group. Generating the content fails if a chunk is outside of the
+/// output root.
+#[turbo_tasks::value]
+pub struct EntryChunksManifest {
+    path: Vc<FileSystemPath>,
+    output_root: Vc<FileSystemPath>,
+    entries: Vec<(RcStr, Vc<OutputAssets>)>,
+}
+
+#[turbo_tasks::value_impl]
+impl EntryChunksManifest {
+    #[turbo_tasks::function]
+    pub fn new(
+        path: Vc<FileSystemPath>,
+        output_root: Vc<FileSystemPath>,
+        entries: Vec<(RcStr, Vc<OutputAssets>)>,
+    ) -> Vc<Self> {
+        EntryChunksManifest {
+            path,
+            output_root,
+            entries,
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for EntryChunksManifest {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        AssetIdent::from_path(self.path)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for EntryChunksManifest {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let output_root = &*self.output_root.await?;
+        let entries = self
+            .entries
+            .iter()
+            .map(|(name, chunks)| async move {
+                let paths = chunks
+                    .await?
+                    .iter()
+                    .map(|chunk| async move { chunk.ident().path().await })
+                    .try_join()
+                    .await?;
+                let paths = paths
+                    .iter()
+                    .map(|path| {
+                        let Some(relative) = output_root.get_path_to(path) else {
+                            bail!(
+                                "chunk {} of entry {name} is outside of the output root {}",
+                                path.path,
+                                output_root.path
+                            );
+                        };
+                        Ok(RcStr::from(relative))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                anyhow::Ok((name.clone(), paths))
+            })
+            .try_join()
+            .await?;
+
+        let json = entry_chunks_manifest_json(entries)?;
+        Ok(AssetContent::file(File::from(json).into()))
+    }
+}
+
+fn entry_chunks_manifest_json(entries: Vec<(RcStr, Vec<RcStr>)>) -> Result<String> {
+    let entries = entries.into_iter().collect::<BTreeMap<_, _>>();
+    Ok(serde_json::to_string_pretty(&entries)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::entry_chunks_manifest_json;
+
+    #[test]
+    fn entries_are_sorted_by_name() {
+        let json = entry_chunks_manifest_json(vec![
+            (
+                "main".into(),
+                vec!["chunks/main.js".into(), "chunks/shared.js".into()],
+            ),
+            (
+                "admin".into(),
+                vec!["chunks/admin.js".into(), "chunks/shared.js".into()],
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            json,
+            r#"{
+  "admin": [
+    "chunks/admin.js",
+    "chunks/shared.js"
+  ],
+  "main": [
+    "chunks/main.js",
+    "chunks/shared.js"
+  ]
+}"#
+        );
+    }
+}
@@ -5,6 +5,7 @@ pub mod chunking;
 pub(crate) mod chunking_context;
 pub(crate) mod containment_tree;
 pub(crate) mod data;
+pub(crate) mod entry_manifest;
 pub(crate) mod evaluate;
 pub mod module_id_strategies;
 pub mod optimize;
@@ -33,9 +34,11 @@ use turbo_tasks_hash::DeterministicHash;
 use self::{availability_info::AvailabilityInfo, available_chunk_items::AvailableChunkItems};
 pub use self::{
     chunking_context::{
-        ChunkGroupResult, ChunkingContext, ChunkingContextExt, EntryChunkGroupResult, MinifyType,
+        base_path_with_trailing_slash, ChunkGroupResult, ChunkingContext, ChunkingContextExt,
+        EntryChunkGroupResult, MinifyType,
     },
     data::{ChunkData, ChunkDataOption, ChunksData},
+    entry_manifest::EntryChunksManifest,
     evaluate::{EvaluatableAsset, EvaluatableAssetExt, EvaluatableAssets},
 };
 use crate::{
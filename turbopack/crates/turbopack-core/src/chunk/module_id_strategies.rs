@@ -38,6 +38,15 @@ impl GlobalModuleIdStrategy {
     pub async fn new(module_id_map: HashMap<RcStr, Vc<ModuleId>>) -> Result<Vc<Self>> {
         Ok(GlobalModuleIdStrategy { module_id_map }.cell())
     }
+
+    /// A strategy without precomputed ids, which hashes the ident of every
+    /// module.
+    pub fn hashed() -> Vc<Self> {
+        GlobalModuleIdStrategy {
+            module_id_map: HashMap::new(),
+        }
+        .cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
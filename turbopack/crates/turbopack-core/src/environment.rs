@@ -36,6 +36,22 @@ pub enum ChunkLoading {
     Dom,
 }
 
+/// Globals that are guaranteed to be available in an [`Environment`].
+///
+/// A capability that is `false` is not guaranteed to be missing, as it might
+/// still be polyfilled at runtime. Only Node.js targets report capabilities,
+/// since their runtime version is known exactly.
+#[turbo_tasks::value(shared)]
+#[derive(Default, Debug, Clone, Copy)]
+pub struct RuntimeCapabilities {
+    /// A global `fetch` function.
+    pub fetch: bool,
+    /// A global `Buffer` class.
+    pub buffer: bool,
+    /// A global `WebSocket` class.
+    pub web_socket: bool,
+}
+
 #[turbo_tasks::value]
 pub struct Environment {
     // members must be private to avoid leaking non-custom types
@@ -212,6 +228,33 @@ impl Environment {
         })
     }
 
+    #[turbo_tasks::function]
+    pub async fn runtime_capabilities(self: Vc<Self>) -> Result<Vc<RuntimeCapabilities>> {
+        let env = self.await?;
+        Ok(match env.execution {
+            ExecutionEnvironment::NodeJsBuildTime(node_env)
+            | ExecutionEnvironment::NodeJsLambda(node_env) => {
+                // `fetch` and `WebSocket` became globals in Node.js 18 and 22.
+                let major = node_env
+                    .runtime_versions()
+                    .await?
+                    .node
+                    .map(|version| version.major);
+                RuntimeCapabilities {
+                    fetch: major.is_some_and(|major| major >= 18),
+                    buffer: true,
+                    web_socket: major.is_some_and(|major| major >= 22),
+                }
+                .cell()
+            }
+            // A browserslist query doesn't guarantee which globals exist, and edge runtimes
+            // differ between providers.
+            ExecutionEnvironment::EdgeWorker(_)
+            | ExecutionEnvironment::Browser(_)
+            | ExecutionEnvironment::Custom(_) => RuntimeCapabilities::default().cell(),
+        })
+    }
+
     #[turbo_tasks::function]
     pub async fn chunk_loading(self: Vc<Self>) -> Result<Vc<ChunkLoading>> {
         let env = self.await?;
@@ -1,5 +1,6 @@
 pub mod analyze;
 pub mod code_gen;
+pub mod read_glob;
 pub mod resolve;
 
 use std::{
@@ -278,6 +279,12 @@ impl IssueProcessingPath for RootIssueProcessingPath {
     }
 }
 
+/// The maximum number of items kept in an issue processing path.
+///
+/// Processing paths are computed for every captured issue, so on deep module
+/// graphs only the items closest to the issue are kept.
+pub const MAX_ISSUE_PROCESSING_PATH_DEPTH: usize = 32;
+
 #[turbo_tasks::value]
 struct ItemIssueProcessingPath(
     Option<Vc<IssueProcessingPathItem>>,
@@ -286,7 +293,8 @@ struct ItemIssueProcessingPath(
 
 #[turbo_tasks::value_impl]
 impl IssueProcessingPath for ItemIssueProcessingPath {
-    /// Returns the shortest path from the root issue to the given issue.
+    /// Returns the shortest path from the root issue to the given issue, capped
+    /// to [MAX_ISSUE_PROCESSING_PATH_DEPTH] items.
     #[turbo_tasks::function]
     async fn shortest_path(
         &self,
@@ -326,12 +334,11 @@ impl IssueProcessingPath for ItemIssueProcessingPath {
                 shortest = Some(path);
             }
         }
-        Ok(Vc::cell(shortest.map(|path| {
-            if let Some(item) = self.0 {
+        Ok(Vc::cell(shortest.map(|path| match self.0 {
+            Some(item) if path.len() < MAX_ISSUE_PROCESSING_PATH_DEPTH => {
                 std::iter::once(item).chain(path.iter().copied()).collect()
-            } else {
-                path.clone()
             }
+            _ => path.clone(),
         })))
     }
 }
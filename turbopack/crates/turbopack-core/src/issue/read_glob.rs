@@ -0,0 +1,63 @@
+use anyhow::Result;
+use turbo_tasks::Vc;
+use turbo_tasks_fs::{glob::Glob, FileSystemPath, ReadGlobLimits, ReadGlobResult};
+
+use super::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString};
+
+/// Emitted when the result of a glob was truncated because it exceeded the
+/// [ReadGlobLimits].
+#[turbo_tasks::value(shared)]
+pub struct ReadGlobTruncatedIssue {
+    pub path: Vc<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for ReadGlobTruncatedIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Error.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("Glob matched too many files".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Load.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(
+                "The glob exceeded the maximum number of entries or directory depth, so its \
+                 results are incomplete. Use a more specific glob."
+                    .into(),
+            )
+            .cell(),
+        ))
+    }
+}
+
+/// Reads a glob like [FileSystemPath::read_glob_with_limits], but emits a
+/// [ReadGlobTruncatedIssue] when the results were truncated.
+#[turbo_tasks::function]
+pub async fn read_glob_or_emit_issue(
+    directory: Vc<FileSystemPath>,
+    glob: Vc<Glob>,
+    include_dot_files: bool,
+    limits: Vc<ReadGlobLimits>,
+) -> Result<Vc<ReadGlobResult>> {
+    let result = directory.read_glob_with_limits(glob, include_dot_files, limits);
+    if *result.is_truncated().await? {
+        ReadGlobTruncatedIssue { path: directory }.cell().emit();
+    }
+    Ok(result)
+}
@@ -20,6 +20,13 @@ pub trait OutputAsset: Asset {
     fn references(self: Vc<Self>) -> Vc<OutputAssets> {
         OutputAssets::empty()
     }
+
+    /// Companion files that are emitted alongside this [OutputAsset], e. g. a
+    /// `.d.ts` or a license file. They are included in the same walks as
+    /// [OutputAsset::references], but don't imply a dependency of this asset.
+    fn auxiliary_assets(self: Vc<Self>) -> Vc<OutputAssets> {
+        OutputAssets::empty()
+    }
 }
 
 #[turbo_tasks::value(transparent)]
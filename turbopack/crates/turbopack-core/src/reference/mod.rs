@@ -28,8 +28,35 @@ pub use source_map::SourceMapReference;
 #[turbo_tasks::value_trait]
 pub trait ModuleReference: ValueToString {
     fn resolve_reference(self: Vc<Self>) -> Vc<ModuleResolveResult>;
-    // TODO think about different types
-    // fn kind(&self) -> Vc<AssetReferenceType>;
+
+    /// The kind of the reference, e. g. whether it's an ESM import or a
+    /// CommonJS require.
+    fn kind(self: Vc<Self>) -> Vc<AssetReferenceType> {
+        AssetReferenceType::Unknown.cell()
+    }
+}
+
+/// The kind of a [ModuleReference], as reported by [ModuleReference::kind].
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Clone, Copy, Hash)]
+pub enum AssetReferenceType {
+    /// A static ESM `import` or `export ... from`.
+    EsmImport,
+    /// A CommonJS `require()` or `require.resolve()`.
+    CjsRequire,
+    /// A dynamic `import()`.
+    DynamicImport,
+    /// A CSS `@import` or `composes: ... from`.
+    Css,
+    /// A `url()` in CSS or a `new URL(..., import.meta.url)`.
+    Url,
+    /// A `new Worker(...)`.
+    Worker,
+    /// A reference that only provides types, e. g. a TypeScript
+    /// `/// <reference types="..." />`. It's not needed at runtime.
+    TypeOnly,
+    /// Any other reference.
+    Unknown,
 }
 
 /// Multiple [ModuleReference]s
@@ -137,6 +164,21 @@ impl SingleOutputAssetReference {
     }
 }
 
+/// Returns the module a reference trivially resolves to, without calling
+/// [ModuleReference::resolve_reference]. This avoids scheduling a resolve task
+/// for references that already hold their module, like
+/// [SingleModuleReference]. Returns `None` for all other references.
+pub async fn trivially_resolved_module(
+    reference: Vc<Box<dyn ModuleReference>>,
+) -> Result<Option<Vc<Box<dyn Module>>>> {
+    if let Some(reference) =
+        Vc::try_resolve_downcast_type::<SingleModuleReference>(reference).await?
+    {
+        return Ok(Some(reference.await?.asset.resolve().await?));
+    }
+    Ok(None)
+}
+
 /// Aggregates all [Module]s referenced by an [Module]. [ModuleReference]
 /// This does not include transitively references [Module]s, but it includes
 /// primary and secondary [Module]s referenced.
@@ -147,20 +189,27 @@ pub async fn referenced_modules_and_affecting_sources(
     module: Vc<Box<dyn Module>>,
 ) -> Result<Vc<Modules>> {
     let references_set = module.references().await?;
-    let mut modules = IndexSet::new();
-    let resolve_results = references_set
+    let modules = references_set
         .iter()
-        .map(|r| r.resolve_reference())
+        .map(|&reference| async move {
+            if let Some(module) = trivially_resolved_module(reference).await? {
+                return Ok(vec![module]);
+            }
+            let resolve_result = reference.resolve_reference().await?;
+            Ok(resolve_result
+                .primary_modules_iter()
+                .chain(
+                    resolve_result
+                        .affecting_sources_iter()
+                        .map(|source| Vc::upcast(RawModule::new(source))),
+                )
+                .collect::<Vec<_>>())
+        })
         .try_join()
-        .await?;
-    for resolve_result in resolve_results {
-        modules.extend(resolve_result.primary_modules_iter());
-        modules.extend(
-            resolve_result
-                .affecting_sources_iter()
-                .map(|source| Vc::upcast(RawModule::new(source))),
-        );
-    }
+        .await?
+        .into_iter()
+        .flatten()
+        .collect::<IndexSet<_>>();
     let mut resolved_modules = IndexSet::new();
     for module in modules {
         resolved_modules.insert(module.resolve().await?);
@@ -174,13 +223,31 @@ pub async fn referenced_modules_and_affecting_sources(
 ///
 /// [Module]: crate::module::Module
 #[turbo_tasks::function]
-pub async fn primary_referenced_modules(module: Vc<Box<dyn Module>>) -> Result<Vc<Modules>> {
+pub fn primary_referenced_modules(module: Vc<Box<dyn Module>>) -> Vc<Modules> {
+    primary_referenced_modules_with_types(module, true)
+}
+
+/// Like [primary_referenced_modules], but skips references of kind
+/// [AssetReferenceType::TypeOnly] unless `include_type_only` is set.
+#[turbo_tasks::function]
+pub async fn primary_referenced_modules_with_types(
+    module: Vc<Box<dyn Module>>,
+    include_type_only: bool,
+) -> Result<Vc<Modules>> {
     let mut set = HashSet::new();
     let modules = module
         .references()
         .await?
         .iter()
-        .map(|reference| async {
+        .map(|&reference| async move {
+            if !include_type_only
+                && matches!(*reference.kind().await?, AssetReferenceType::TypeOnly)
+            {
+                return Ok(vec![]);
+            }
+            if let Some(module) = trivially_resolved_module(reference).await? {
+                return Ok(vec![module]);
+            }
             Ok(reference
                 .resolve_reference()
                 .resolve()
@@ -240,7 +307,8 @@ pub async fn all_assets_from_entries(entries: Vc<OutputAssets>) -> Result<Vc<Out
     ))
 }
 
-/// Computes the list of all chunk children of a given chunk.
+/// Computes the list of all chunk children of a given chunk, including its
+/// auxiliary assets.
 pub async fn get_referenced_assets(
     asset: Vc<Box<dyn OutputAsset>>,
 ) -> Result<impl Iterator<Item = Vc<Box<dyn OutputAsset>>> + Send> {
@@ -248,6 +316,7 @@ pub async fn get_referenced_assets(
         .references()
         .await?
         .iter()
+        .chain(asset.auxiliary_assets().await?.iter())
         .copied()
         .collect::<Vec<_>>()
         .into_iter())
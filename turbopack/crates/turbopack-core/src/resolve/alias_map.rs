@@ -904,6 +904,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_exports_subpath_patterns() {
+        let mut map = AliasMap::new();
+        map.insert(AliasPattern::parse("./features/*"), "./src/features/*.js");
+        map.insert(
+            AliasPattern::parse("./features/nested/*"),
+            "./src/features/nested/*.mjs",
+        );
+        map.insert(
+            AliasPattern::parse("./features/special"),
+            "./src/special/index.js",
+        );
+
+        assert_alias_matches!(map, "./features");
+        assert_alias_matches!(
+            map,
+            "./features/foo",
+            replaced_owned("./src/features/foo.js")
+        );
+        assert_alias_matches!(
+            map,
+            "./features/special",
+            // The exact key has the longer prefix, so it should come first.
+            exact("./src/special/index.js"),
+            replaced_owned("./src/features/special.js"),
+        );
+        assert_alias_matches!(
+            map,
+            "./features/nested/bar",
+            // The longer prefix should come first.
+            replaced_owned("./src/features/nested/bar.mjs"),
+            replaced_owned("./src/features/nested/bar.js"),
+        );
+    }
+
     #[test]
     fn test_pattern() {
         let mut map = AliasMap::new();
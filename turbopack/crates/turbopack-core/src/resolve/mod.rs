@@ -221,6 +221,12 @@ impl ModuleResolveResult {
     pub fn is_unresolveable_ref(&self) -> bool {
         self.primary.is_empty()
     }
+
+    /// Returns the first module of the result, i. e. the module of a single
+    /// result or of the first alternative that resolved to a module.
+    pub fn first_module_ref(&self) -> Option<Vc<Box<dyn Module>>> {
+        self.primary_modules_iter().next()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -326,13 +332,7 @@ impl ModuleResolveResult {
 
     #[turbo_tasks::function]
     pub async fn first_module(self: Vc<Self>) -> Result<Vc<OptionModule>> {
-        let this = self.await?;
-        Ok(Vc::cell(this.primary.iter().find_map(
-            |(_, item)| match item {
-                &ModuleResolveResultItem::Module(a) => Some(a),
-                _ => None,
-            },
-        )))
+        Ok(Vc::cell(self.await?.first_module_ref()))
     }
 
     /// Returns a set (no duplicates) of primary modules in the result. All
@@ -629,6 +629,15 @@ impl ResolveResult {
         self.primary.is_empty()
     }
 
+    /// Returns the first source of the result, i. e. the source of a single
+    /// result or of the first alternative that resolved to a source.
+    pub fn first_source_ref(&self) -> Option<Vc<Box<dyn Source>>> {
+        self.primary.values().find_map(|item| match item {
+            &ResolveResultItem::Source(source) => Some(source),
+            _ => None,
+        })
+    }
+
     pub async fn map<A, AF, R, RF>(&self, source_fn: A, affecting_source_fn: R) -> Result<Self>
     where
         A: Fn(Vc<Box<dyn Source>>) -> AF,
@@ -859,14 +868,7 @@ impl ResolveResult {
 
     #[turbo_tasks::function]
     pub async fn first_source(self: Vc<Self>) -> Result<Vc<OptionSource>> {
-        let this = self.await?;
-        Ok(Vc::cell(this.primary.iter().find_map(|(_, item)| {
-            if let &ResolveResultItem::Source(a) = item {
-                Some(a)
-            } else {
-                None
-            }
-        })))
+        Ok(Vc::cell(self.await?.first_source_ref()))
     }
 
     #[turbo_tasks::function]
@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, future::Future, pin::Pin};
+use std::{collections::BTreeMap, future::Future, mem::take, pin::Pin};
 
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
@@ -493,6 +493,10 @@ pub struct ResolveOptions {
     pub placeholder_for_future_extensions: (),
 }
 
+/// Main fields preferring ES modules, which allows to tree-shake packages
+/// that ship both CommonJS and ESM.
+pub const ESM_MAIN_FIELDS: [&str; 3] = ["module", "jsnext:main", "main"];
+
 #[turbo_tasks::value_impl]
 impl ResolveOptions {
     /// Returns a new [Vc<ResolveOptions>] with its import map extended to
@@ -548,6 +552,35 @@ impl ResolveOptions {
         resolve_options.fully_specified = fully_specified;
         Ok(resolve_options.cell())
     }
+
+    /// Replaces the [main]-like fields to try, in order, when resolving into
+    /// a package. Like webpack's `resolve.mainFields`, `"..."` stands for the
+    /// previously configured main fields.
+    ///
+    /// [main]: https://nodejs.org/api/packages.html#main
+    #[turbo_tasks::function]
+    pub async fn with_main_fields(self: Vc<Self>, main_fields: Vec<RcStr>) -> Result<Vc<Self>> {
+        let mut resolve_options = self.await?.clone_value();
+        let mut old = Vec::new();
+        resolve_options.into_package.retain(|item| {
+            if matches!(item, ResolveIntoPackage::MainField { .. }) {
+                old.push(item.clone());
+                false
+            } else {
+                true
+            }
+        });
+        for field in main_fields {
+            if &*field == "..." {
+                resolve_options.into_package.extend(take(&mut old));
+            } else {
+                resolve_options
+                    .into_package
+                    .push(ResolveIntoPackage::MainField { field });
+            }
+        }
+        Ok(resolve_options.cell())
+    }
 }
 
 #[turbo_tasks::value(shared)]
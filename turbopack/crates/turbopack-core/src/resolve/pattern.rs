@@ -1501,9 +1501,10 @@ pub async fn read_matches(
                 prefix.pop();
                 prefix.pop();
             }
-            match &*lookup_dir.read_dir().await? {
-                DirectoryContent::Entries(map) => {
-                    for (key, entry) in map.iter() {
+            let dir = lookup_dir.read_dir().await?;
+            match &*dir {
+                DirectoryContent::Entries(_) => {
+                    for (key, entry) in dir.sorted_entries() {
                         match entry {
                             DirectoryEntry::File(path) => {
                                 let len = prefix.len();
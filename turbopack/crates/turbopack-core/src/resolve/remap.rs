@@ -120,6 +120,14 @@ impl SubpathValue {
     /// uses the `conditions` to skip or enter conditional results.
     /// The state of conditions is stored within `condition_overrides`, which is
     /// also exposed to the consumer.
+    ///
+    /// Like in Node.js, conditions are tried in the order of the object keys,
+    /// not in the order of `conditions`, and `default` always matches. A
+    /// condition that is [ConditionValue::Unknown] (either explicitly or via
+    /// `unspecified_condition`) adds the results of its branch guarded by that
+    /// condition and continues with the following keys, so a later `default`
+    /// is added as the fallback. Returns `false` when no branch matched, which
+    /// makes the request unresolveable.
     pub fn add_results<'a>(
         &'a self,
         conditions: &BTreeMap<RcStr, ConditionValue>,
@@ -551,3 +559,88 @@ impl<'a> IntoIterator for &'a ResolveAliasMap {
         (&self.0).into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use serde_json::json;
+    use turbo_tasks::RcStr;
+
+    use super::{ExportImport, SubpathValue};
+    use crate::resolve::options::ConditionValue;
+
+    fn conditions(list: &[(&str, ConditionValue)]) -> BTreeMap<RcStr, ConditionValue> {
+        list.iter()
+            .map(|(name, value)| ((*name).into(), *value))
+            .collect()
+    }
+
+    #[test]
+    fn conditional_falls_back_to_default() {
+        let value = SubpathValue::try_new(
+            &json!({ "import": "./esm.js", "node": "./node.js", "default": "./index.js" }),
+            ExportImport::Export,
+        )
+        .unwrap();
+        let conditions = conditions(&[
+            ("import", ConditionValue::Unset),
+            ("node", ConditionValue::Unset),
+        ]);
+
+        let mut overrides = HashMap::new();
+        let mut target = Vec::new();
+        assert!(value.add_results(
+            &conditions,
+            &ConditionValue::Unset,
+            &mut overrides,
+            &mut target
+        ));
+        assert_eq!(target, vec![("./index.js", vec![])]);
+    }
+
+    #[test]
+    fn conditional_without_matching_branch() {
+        let value = SubpathValue::try_new(
+            &json!({ "import": "./esm.js", "node": "./node.js" }),
+            ExportImport::Export,
+        )
+        .unwrap();
+        let conditions = conditions(&[("import", ConditionValue::Unset)]);
+
+        let mut overrides = HashMap::new();
+        let mut target = Vec::new();
+        assert!(!value.add_results(
+            &conditions,
+            &ConditionValue::Unset,
+            &mut overrides,
+            &mut target
+        ));
+        assert!(target.is_empty());
+    }
+
+    #[test]
+    fn conditional_unknown_condition_keeps_default() {
+        let value = SubpathValue::try_new(
+            &json!({ "node": "./node.js", "default": "./index.js" }),
+            ExportImport::Export,
+        )
+        .unwrap();
+
+        let mut overrides = HashMap::new();
+        let mut target = Vec::new();
+        assert!(value.add_results(
+            &BTreeMap::new(),
+            &ConditionValue::Unknown,
+            &mut overrides,
+            &mut target
+        ));
+        assert_eq!(
+            target,
+            vec![
+                ("./node.js", vec![("node", true)]),
+                ("./index.js", vec![("node", false)]),
+            ]
+        );
+    }
+}
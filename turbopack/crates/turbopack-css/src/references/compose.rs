@@ -2,7 +2,7 @@ use anyhow::Result;
 use turbo_tasks::{RcStr, Value, ValueToString, Vc};
 use turbopack_core::{
     chunk::ChunkableModuleReference,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::CssReferenceSubType,
     resolve::{origin::ResolveOrigin, parse::Request, ModuleResolveResult},
 };
@@ -40,6 +40,11 @@ impl ModuleReference for CssModuleComposeReference {
             None,
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::Css.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -15,7 +15,7 @@ use turbo_tasks::{RcStr, Value, ValueToString, Vc};
 use turbopack_core::{
     chunk::{ChunkableModuleReference, ChunkingContext},
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::{CssReferenceSubType, ImportContext},
     resolve::{origin::ResolveOrigin, parse::Request, ModuleResolveResult},
 };
@@ -241,6 +241,11 @@ impl ModuleReference for ImportAssetReference {
             Some(self.issue_source),
         ))
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::Css.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use turbo_tasks::{RcStr, ValueToString, Vc};
 use turbopack_core::{
-    chunk::ChunkableModuleReference, module::Module, reference::ModuleReference,
+    chunk::ChunkableModuleReference,
+    module::Module,
+    reference::{AssetReferenceType, ModuleReference},
     resolve::ModuleResolveResult,
 };
 
@@ -27,6 +29,11 @@ impl ModuleReference for InternalCssAssetReference {
     fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
         ModuleResolveResult::module(self.module).cell()
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::Css.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
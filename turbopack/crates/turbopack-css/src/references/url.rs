@@ -19,7 +19,7 @@ use turbopack_core::{
     ident::AssetIdent,
     issue::{IssueSeverity, IssueSource},
     output::OutputAsset,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::{ReferenceType, UrlReferenceSubType},
     resolve::{origin::ResolveOrigin, parse::Request, url_resolve, ModuleResolveResult},
 };
@@ -93,6 +93,11 @@ impl ModuleReference for UrlAssetReference {
             IssueSeverity::Error.cell(),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::Url.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
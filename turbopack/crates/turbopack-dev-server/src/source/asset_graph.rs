@@ -134,6 +134,7 @@ async fn expand(
                 assets_set.insert(root_asset);
                 if expanded {
                     queue.push_back(root_asset.references());
+                    queue.push_back(root_asset.auxiliary_assets());
                 }
             }
         }
@@ -145,6 +146,7 @@ async fn expand(
                     assets.push((sub_path, root_asset));
                 }
                 queue.push_back(root_asset.references());
+                queue.push_back(root_asset.auxiliary_assets());
                 assets_set.insert(root_asset);
             }
         }
@@ -167,6 +169,7 @@ async fn expand(
                     };
                     if expanded {
                         queue.push_back(asset.references());
+                        queue.push_back(asset.auxiliary_assets());
                     }
                     for sub_path in sub_paths_buffer.into_iter().take(sub_paths) {
                         assets.push((sub_path, *asset));
@@ -45,12 +45,13 @@ impl StaticAssetsContentSource {
 #[turbo_tasks::function]
 async fn get_routes_from_directory(dir: Vc<FileSystemPath>) -> Result<Vc<RouteTree>> {
     let dir = dir.read_dir().await?;
-    let DirectoryContent::Entries(entries) = &*dir else {
+    let DirectoryContent::Entries(_) = &*dir else {
         return Ok(RouteTree::empty());
     };
 
-    let routes = entries
-        .iter()
+    let routes = dir
+        .sorted_entries()
+        .into_iter()
         .flat_map(|(name, entry)| match entry {
             DirectoryEntry::File(path) | DirectoryEntry::Symlink(path) => {
                 Some(RouteTree::new_route(
@@ -4,6 +4,7 @@ use anyhow::Result;
 use indoc::writedoc;
 use turbo_tasks::{RcStr, Vc};
 use turbopack_core::{
+    chunk::base_path_with_trailing_slash,
     code_builder::{Code, CodeBuilder},
     context::AssetContext,
     environment::{ChunkLoading, Environment},
@@ -49,7 +50,8 @@ pub async fn get_browser_runtime_code(
     let mut code: CodeBuilder = CodeBuilder::default();
     let output_root = output_root.await?.to_string();
     let chunk_base_path = &*chunk_base_path.await?;
-    let chunk_base_path = chunk_base_path.as_ref().map_or_else(|| "", |f| f.as_str());
+    let chunk_base_path =
+        base_path_with_trailing_slash(chunk_base_path.as_ref().map_or_else(|| "", |f| f.as_str()));
 
     writedoc!(
         code,
@@ -63,8 +65,8 @@ pub async fn get_browser_runtime_code(
             const RUNTIME_PUBLIC_PATH = {};
             const OUTPUT_ROOT = {};
         "#,
-        StringifyJs(chunk_base_path),
-        StringifyJs(chunk_base_path),
+        StringifyJs(&chunk_base_path),
+        StringifyJs(&chunk_base_path),
         StringifyJs(output_root.as_str()),
     )?;
 
@@ -402,6 +402,13 @@ pub fn replace_builtin(value: &mut JsValue) -> bool {
                                     return true;
                                 }
                             }
+                            // The Array.prototype.forEach method
+                            "forEach" => {
+                                if let Some(JsValue::Function(..)) = args.first() {
+                                    *value = JsValue::Constant(ConstantValue::Undefined);
+                                    return true;
+                                }
+                            }
                             _ => {}
                         }
                     }
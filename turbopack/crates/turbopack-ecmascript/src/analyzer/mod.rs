@@ -1730,6 +1730,14 @@ impl JsValue {
                         "os.endianness".to_string(),
                         "The Node.js os.endianness method: https://nodejs.org/api/os.html#os_os_endianness",
                     ),
+                    WellKnownFunctionKind::OsHomedir => (
+                        "os.homedir".to_string(),
+                        "The Node.js os.homedir method: https://nodejs.org/api/os.html#oshomedir",
+                    ),
+                    WellKnownFunctionKind::OsTmpdir => (
+                        "os.tmpdir".to_string(),
+                        "The Node.js os.tmpdir method: https://nodejs.org/api/os.html#ostmpdir",
+                    ),
                     WellKnownFunctionKind::ProcessCwd => (
                         "process.cwd".to_string(),
                         "The Node.js process.cwd method: https://nodejs.org/api/process.html#processcwd",
@@ -3785,6 +3793,8 @@ pub enum WellKnownFunctionKind {
     OsArch,
     OsPlatform,
     OsEndianness,
+    OsHomedir,
+    OsTmpdir,
     ProcessCwd,
     NodePreGypFind,
     NodeGypBuild,
@@ -3955,17 +3965,21 @@ mod tests {
         },
         testing::{fixture, run_test, NormalizedOutput},
     };
-    use turbo_tasks::{util::FormatDuration, Value};
+    use turbo_tasks::{util::FormatDuration, Value, Vc};
     use turbopack_core::{
         compile_time_info::CompileTimeInfo,
-        environment::{Environment, ExecutionEnvironment, NodeJsEnvironment},
+        environment::{
+            BrowserEnvironment, Environment, ExecutionEnvironment, NodeJsEnvironment,
+            NodeJsVersion, RuntimeCapabilities,
+        },
         target::{Arch, CompileTarget, Endianness, Libc, Platform},
     };
 
     use super::{
         graph::{create_graph, ConditionalKind, Effect, EffectArg, EvalContext, VarGraph},
         linker::link,
-        JsValue,
+        well_known::{well_known_function_call, well_known_object_member},
+        JsValue, WellKnownObjectKind,
     };
 
     #[fixture("tests/analyzer/graph/**/input.js")]
@@ -4259,6 +4273,141 @@ mod tests {
         .unwrap();
     }
 
+    fn os_module_member(platform: Platform, prop: &str) -> (JsValue, Option<JsValue>) {
+        crate::register();
+        let r = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        r.block_on(turbo_tasks_testing::VcStorage::with(async {
+            let compile_time_info = CompileTimeInfo::builder(Environment::new(Value::new(
+                ExecutionEnvironment::NodeJsLambda(
+                    NodeJsEnvironment {
+                        compile_target: CompileTarget {
+                            arch: Arch::X64,
+                            platform,
+                            endianness: Endianness::Little,
+                            libc: Libc::Glibc,
+                        }
+                        .into(),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+            )))
+            .cell();
+            let (member, _) = well_known_object_member(
+                WellKnownObjectKind::OsModule,
+                prop.into(),
+                compile_time_info,
+            )
+            .await?;
+            let called = if let JsValue::WellKnownFunction(kind) = &member {
+                Some(
+                    well_known_function_call(
+                        kind.clone(),
+                        JsValue::unknown_empty(false, "this is not analyzed yet"),
+                        vec![],
+                        compile_time_info,
+                    )
+                    .await?,
+                )
+            } else {
+                None
+            };
+            anyhow::Ok((member, called))
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn os_eol_folds_per_target() {
+        let (eol, _) = os_module_member(Platform::Linux, "EOL");
+        assert_eq!(eol.as_str(), Some("\n"));
+        let (eol, _) = os_module_member(Platform::Darwin, "EOL");
+        assert_eq!(eol.as_str(), Some("\n"));
+        let (eol, _) = os_module_member(Platform::Win32, "EOL");
+        assert_eq!(eol.as_str(), Some("\r\n"));
+        let (eol, _) = os_module_member(Platform::Unknown, "EOL");
+        assert!(eol.is_unknown());
+    }
+
+    #[test]
+    fn os_path_calls_stay_unknown() {
+        for prop in ["homedir", "tmpdir"] {
+            let (_, called) = os_module_member(Platform::Linux, prop);
+            let called = called.expect("os path methods should be well known functions");
+            assert!(called.is_unknown(), "os.{prop}() should not be folded");
+            assert!(called.as_str().is_none());
+        }
+    }
+
+    #[test]
+    fn os_platform_and_arch_fold_to_target() {
+        let (_, platform) = os_module_member(Platform::Win32, "platform");
+        assert_eq!(platform.unwrap().as_str(), Some("win32"));
+        let (_, arch) = os_module_member(Platform::Linux, "arch");
+        assert_eq!(arch.unwrap().as_str(), Some("x64"));
+    }
+
+    /// Returns the runtime capabilities of a Node.js environment with the given
+    /// version, or of a browser environment when no version is given.
+    fn runtime_capabilities(node_version: Option<&'static str>) -> RuntimeCapabilities {
+        crate::register();
+        let r = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        r.block_on(turbo_tasks_testing::VcStorage::with(async {
+            let execution = match node_version {
+                Some(version) => ExecutionEnvironment::NodeJsLambda(
+                    NodeJsEnvironment {
+                        node_version: NodeJsVersion::Static(Vc::cell(version.into())).cell(),
+                        ..Default::default()
+                    }
+                    .into(),
+                ),
+                None => ExecutionEnvironment::Browser(
+                    BrowserEnvironment {
+                        dom: true,
+                        web_worker: false,
+                        service_worker: false,
+                        browserslist_query: "last 1 Chrome versions".into(),
+                    }
+                    .into(),
+                ),
+            };
+            let capabilities = Environment::new(Value::new(execution))
+                .runtime_capabilities()
+                .await?;
+            anyhow::Ok(*capabilities)
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn runtime_capabilities_follow_node_version() {
+        let node16 = runtime_capabilities(Some("16.0.0"));
+        assert!(!node16.fetch);
+        assert!(node16.buffer);
+        assert!(!node16.web_socket);
+
+        let node18 = runtime_capabilities(Some("18.17.0"));
+        assert!(node18.fetch);
+        assert!(node18.buffer);
+        assert!(!node18.web_socket);
+
+        let node22 = runtime_capabilities(Some("22.0.0"));
+        assert!(node22.fetch);
+        assert!(node22.web_socket);
+    }
+
+    #[test]
+    fn runtime_capabilities_of_browser() {
+        let browser = runtime_capabilities(None);
+        assert!(!browser.fetch);
+        assert!(!browser.buffer);
+        assert!(!browser.web_socket);
+    }
+
     async fn resolve(var_graph: &VarGraph, val: JsValue) -> JsValue {
         turbo_tasks_testing::VcStorage::with(async {
             let compile_time_info = CompileTimeInfo::builder(Environment::new(Value::new(
@@ -2,7 +2,7 @@ use std::mem::take;
 
 use anyhow::Result;
 use turbo_tasks::Vc;
-use turbopack_core::compile_time_info::CompileTimeInfo;
+use turbopack_core::{compile_time_info::CompileTimeInfo, target::Platform};
 use url::Url;
 
 use super::{
@@ -105,6 +105,16 @@ pub async fn well_known_function_call(
             .endianness
             .as_str()
             .into(),
+        WellKnownFunctionKind::OsHomedir => JsValue::unknown(
+            JsValue::call(Box::new(JsValue::WellKnownFunction(kind)), args),
+            true,
+            "os.homedir() depends on the machine running the code and is not known at compile time",
+        ),
+        WellKnownFunctionKind::OsTmpdir => JsValue::unknown(
+            JsValue::call(Box::new(JsValue::WellKnownFunction(kind)), args),
+            true,
+            "os.tmpdir() depends on the machine running the code and is not known at compile time",
+        ),
         WellKnownFunctionKind::NodeExpress => {
             JsValue::WellKnownObject(WellKnownObjectKind::NodeExpressApp)
         }
@@ -582,7 +592,7 @@ pub async fn well_known_object_member(
             child_process_module_member(kind, prop)
         }
         WellKnownObjectKind::OsModule | WellKnownObjectKind::OsModuleDefault => {
-            os_module_member(kind, prop)
+            os_module_member(kind, prop, compile_time_info).await?
         }
         WellKnownObjectKind::NodeProcess => node_process_member(prop, compile_time_info).await?,
         WellKnownObjectKind::NodePreGyp => node_pre_gyp(prop),
@@ -711,11 +721,37 @@ pub fn child_process_module_member(kind: WellKnownObjectKind, prop: JsValue) ->
     }
 }
 
-fn os_module_member(kind: WellKnownObjectKind, prop: JsValue) -> JsValue {
-    match (kind, prop.as_str()) {
+async fn os_module_member(
+    kind: WellKnownObjectKind,
+    prop: JsValue,
+    compile_time_info: Vc<CompileTimeInfo>,
+) -> Result<JsValue> {
+    Ok(match (kind, prop.as_str()) {
         (.., Some("platform")) => JsValue::WellKnownFunction(WellKnownFunctionKind::OsPlatform),
         (.., Some("arch")) => JsValue::WellKnownFunction(WellKnownFunctionKind::OsArch),
         (.., Some("endianness")) => JsValue::WellKnownFunction(WellKnownFunctionKind::OsEndianness),
+        (.., Some("homedir")) => JsValue::WellKnownFunction(WellKnownFunctionKind::OsHomedir),
+        (.., Some("tmpdir")) => JsValue::WellKnownFunction(WellKnownFunctionKind::OsTmpdir),
+        (.., Some("EOL")) => {
+            match compile_time_info
+                .environment()
+                .compile_target()
+                .await?
+                .platform
+            {
+                Platform::Win32 => "\r\n".into(),
+                Platform::Unknown => JsValue::unknown(
+                    JsValue::member(
+                        Box::new(JsValue::WellKnownObject(WellKnownObjectKind::OsModule)),
+                        Box::new(prop),
+                    ),
+                    true,
+                    "os.EOL depends on the target platform, which is not specified in the \
+                     environment",
+                ),
+                _ => "\n".into(),
+            }
+        }
         (WellKnownObjectKind::OsModule, Some("default")) => {
             JsValue::WellKnownObject(WellKnownObjectKind::OsModuleDefault)
         }
@@ -727,7 +763,7 @@ fn os_module_member(kind: WellKnownObjectKind, prop: JsValue) -> JsValue {
             true,
             "unsupported property on Node.js os module",
         ),
-    }
+    })
 }
 
 async fn node_process_member(
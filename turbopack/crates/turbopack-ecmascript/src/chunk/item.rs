@@ -2,7 +2,7 @@ use std::io::Write;
 
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
-use turbo_tasks::{trace::TraceRawVcs, Upcast, ValueToString, Vc};
+use turbo_tasks::{trace::TraceRawVcs, RcStr, Upcast, ValueToString, Vc};
 use turbo_tasks_fs::rope::Rope;
 use turbopack_core::{
     chunk::{AsyncModuleInfo, ChunkItem, ChunkItemExt, ChunkingContext},
@@ -14,7 +14,7 @@ use turbopack_core::{
 
 use crate::{
     references::async_module::{AsyncModuleOptions, OptionAsyncModuleOptions},
-    utils::FormatIter,
+    utils::{FormatIter, StringifyJs},
     EcmascriptModuleContent, EcmascriptOptions,
 };
 
@@ -45,12 +45,24 @@ impl EcmascriptChunkItemContent {
         let content = content.await?;
         let async_module = async_module_options.await?.clone_value();
 
+        let use_strict = content
+            .directives
+            .iter()
+            .any(|d| d.as_str() == "use strict");
+        let directives = content
+            .directives
+            .iter()
+            .filter(|d| d.as_str() != "use strict")
+            .cloned()
+            .collect::<Vec<_>>();
+
         Ok(EcmascriptChunkItemContent {
             inner_code: content.inner_code.clone(),
             source_map: content.source_map,
             options: if content.is_esm {
                 EcmascriptChunkItemOptions {
                     strict: true,
+                    directives,
                     refresh,
                     externals,
                     async_module,
@@ -62,6 +74,8 @@ impl EcmascriptChunkItemContent {
                 }
 
                 EcmascriptChunkItemOptions {
+                    strict: use_strict,
+                    directives,
                     refresh,
                     externals,
                     // These things are not available in ESM
@@ -79,7 +93,15 @@ impl EcmascriptChunkItemContent {
 
     #[turbo_tasks::function]
     pub async fn module_factory(self: Vc<Self>) -> Result<Vc<Code>> {
-        let this = self.await?;
+        Ok(self.await?.module_factory_code()?.cell())
+    }
+}
+
+impl EcmascriptChunkItemContent {
+    /// Builds the module factory, which wraps the inner code in a function
+    /// receiving the runtime helpers. Source map offsets of the inner code are
+    /// tracked by the [CodeBuilder] mappings.
+    pub fn module_factory_code(&self) -> Result<Code> {
         let mut args = vec![
             "r: __turbopack_require__",
             "f: __turbopack_module_context__",
@@ -99,50 +121,52 @@ impl EcmascriptChunkItemContent {
             // HACK
             "__dirname",
         ];
-        if this.options.async_module.is_some() {
+        if self.options.async_module.is_some() {
             args.push("a: __turbopack_async_module__");
         }
-        if this.options.externals {
+        if self.options.externals {
             args.push("x: __turbopack_external_require__");
             args.push("y: __turbopack_external_import__");
         }
-        if this.options.refresh {
+        if self.options.refresh {
             args.push("k: __turbopack_refresh__");
         }
-        if this.options.module || this.options.refresh {
+        if self.options.module || self.options.refresh {
             args.push("m: module");
         }
-        if this.options.exports {
+        if self.options.exports {
             args.push("e: exports");
         }
-        if this.options.require {
+        if self.options.require {
             args.push("t: require");
         }
-        if this.options.wasm {
+        if self.options.wasm {
             args.push("w: __turbopack_wasm__");
             args.push("u: __turbopack_wasm_module__");
         }
         let mut code = CodeBuilder::default();
         let args = FormatIter(|| args.iter().copied().intersperse(", "));
-        if this.options.this {
+        if self.options.this {
             writeln!(code, "(function({{ {} }}) {{ !function() {{", args,)?;
         } else {
             writeln!(code, "(({{ {} }}) => (() => {{", args,)?;
         }
-        if this.options.strict {
-            code += "\"use strict\";\n\n";
-        } else {
-            code += "\n";
+        if self.options.strict {
+            code += "\"use strict\";\n";
+        }
+        for directive in &self.options.directives {
+            writeln!(code, "{};", StringifyJs(directive))?;
         }
+        code += "\n";
 
-        if this.options.async_module.is_some() {
+        if self.options.async_module.is_some() {
             code += "__turbopack_async_module__(async (__turbopack_handle_async_dependencies__, \
                      __turbopack_async_result__) => { try {\n";
         }
 
-        code.push_source(&this.inner_code, this.source_map);
+        code.push_source(&self.inner_code, self.source_map);
 
-        if let Some(opts) = &this.options.async_module {
+        if let Some(opts) = &self.options.async_module {
             write!(
                 code,
                 "__turbopack_async_result__();\n}} catch(e) {{ __turbopack_async_result__(e); }} \
@@ -151,12 +175,12 @@ impl EcmascriptChunkItemContent {
             )?;
         }
 
-        if this.options.this {
+        if self.options.this {
             code += "\n}.call(this) })";
         } else {
             code += "\n})())";
         }
-        Ok(code.build().cell())
+        Ok(code.build())
     }
 }
 
@@ -164,6 +188,10 @@ impl EcmascriptChunkItemContent {
 pub struct EcmascriptChunkItemOptions {
     /// Whether this chunk item should be in "use strict" mode.
     pub strict: bool,
+    /// Other directives from the module's directive prologue, which are
+    /// emitted at the top of the module factory so they stay local to the
+    /// module.
+    pub directives: Vec<RcStr>,
     /// Whether this chunk item's module factory should include a
     /// `__turbopack_refresh__` argument.
     pub refresh: bool,
@@ -268,3 +296,36 @@ pub struct EcmascriptChunkItemsChunk(Vec<Vc<Box<dyn EcmascriptChunkItem>>>);
 
 #[turbo_tasks::value(transparent)]
 pub struct EcmascriptChunkItems(pub(super) Vec<Vc<Box<dyn EcmascriptChunkItem>>>);
+
+#[cfg(test)]
+mod tests {
+    use super::{EcmascriptChunkItemContent, EcmascriptChunkItemOptions};
+
+    #[test]
+    fn module_factory_code() {
+        let content = EcmascriptChunkItemContent {
+            inner_code: "console.log(\"hello\");".into(),
+            options: EcmascriptChunkItemOptions {
+                strict: true,
+                directives: vec!["use client".into()],
+                exports: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let code = content.module_factory_code().unwrap();
+        let code = code.source_code().to_str().unwrap();
+        assert_eq!(
+            code,
+            "(({ r: __turbopack_require__, f: __turbopack_module_context__, i: \
+             __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: \
+             __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: \
+             __turbopack_load__, j: __turbopack_dynamic__, P: \
+             __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: \
+             __turbopack_resolve_module_id_path__, b: __turbopack_worker_blob_url__, g: global, \
+             __dirname, e: exports }) => (() => {\n\"use strict\";\n\"use \
+             client\";\n\nconsole.log(\"hello\");\n})())"
+        );
+    }
+}
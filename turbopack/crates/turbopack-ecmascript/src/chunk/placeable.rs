@@ -1,6 +1,9 @@
 use anyhow::Result;
 use turbo_tasks::{TryFlatJoinIterExt, Vc};
-use turbo_tasks_fs::{glob::Glob, FileJsonContent, FileSystemPath};
+use turbo_tasks_fs::{
+    glob::{Glob, GlobAnchoring},
+    FileJsonContent, FileSystemPath,
+};
 use turbopack_core::{
     asset::Asset,
     chunk::ChunkableModule,
@@ -49,11 +52,12 @@ async fn side_effects_from_package_json(
                     .iter()
                     .filter_map(|side_effect| {
                         if let Some(side_effect) = side_effect.as_str() {
-                            if side_effect.contains('/') {
-                                Some(Glob::new(side_effect.into()))
+                            let anchoring = if side_effect.contains('/') {
+                                GlobAnchoring::Anchored
                             } else {
-                                Some(Glob::new(format!("**/{side_effect}").into()))
-                            }
+                                GlobAnchoring::Unanchored
+                            };
+                            Some(Glob::new_with_anchoring(side_effect.into(), anchoring))
                         } else {
                             SideEffectsInPackageJsonIssue {
                                 path: package_json,
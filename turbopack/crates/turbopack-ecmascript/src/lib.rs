@@ -44,6 +44,7 @@ use path_visitor::ApplyVisitors;
 use references::esm::UrlRewriteBehavior;
 pub use references::{AnalyzeEcmascriptModuleResult, TURBOPACK_HELPER};
 use serde::{Deserialize, Serialize};
+pub use special_cases::{SpecialCase, SpecialCases};
 pub use static_code::StaticEcmascriptCode;
 use swc_core::{
     common::GLOBALS,
@@ -53,8 +54,9 @@ use swc_core::{
     },
 };
 pub use transform::{
-    CustomTransformer, EcmascriptInputTransform, EcmascriptInputTransforms, OptionTransformPlugin,
-    TransformContext, TransformPlugin, UnsupportedServerActionIssue,
+    CustomTransformer, EcmascriptInputTransform, EcmascriptInputTransforms,
+    EcmascriptInputTransformsBuilder, OptionTransformPlugin, TransformContext, TransformPlugin,
+    UnsupportedServerActionIssue,
 };
 use turbo_tasks::{
     trace::TraceRawVcs, RcStr, ReadRef, TaskInput, TryJoinIterExt, Value, ValueToString, Vc,
@@ -87,8 +89,11 @@ use self::{
 };
 use crate::{
     chunk::EcmascriptChunkPlaceable,
-    references::{analyse_ecmascript_module, async_module::OptionAsyncModule},
-    transform::remove_shebang,
+    references::{
+        analyse_ecmascript_module, async_module::OptionAsyncModule,
+        esm::export::emit_ambiguous_star_exports_issues,
+    },
+    transform::{directive_prologue, remove_directives, remove_shebang},
 };
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
@@ -143,6 +148,12 @@ pub struct EcmascriptOptions {
     /// If false, they will reference the whole directory. If true, they won't
     /// reference anything and lead to an runtime error instead.
     pub ignore_dynamic_requests: bool,
+    /// Additional references to add to modules matching a path pattern.
+    pub special_cases: Option<Vc<SpecialCases>>,
+    /// Emit the module's directive prologue (e.g. `"use strict"`) at the top
+    /// of its module factory instead of leaving it in the generated code,
+    /// where code generation might insert statements in front of it.
+    pub preserve_directives: bool,
 }
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
@@ -401,6 +412,7 @@ impl EcmascriptAnalyzable for EcmascriptModuleAsset {
             analyze.source_map,
             analyze.exports,
             async_module_info,
+            self.await?.options,
         ))
     }
 }
@@ -546,6 +558,12 @@ impl Module for EcmascriptModuleAsset {
     #[turbo_tasks::function]
     async fn references(self: Vc<Self>) -> Result<Vc<ModuleReferences>> {
         let analyze = self.analyze().await?;
+        // Reported here instead of in the analysis, which can't expand star exports as
+        // they might be cyclic, and instead of in code generation, which runs once per
+        // chunking context.
+        if let EcmascriptExports::EsmExports(exports) = *analyze.exports.await? {
+            emit_ambiguous_star_exports_issues(exports, self.ident()).await?;
+        }
         let references = analyze.references.await?.iter().copied().collect();
         Ok(Vc::cell(references))
     }
@@ -708,6 +726,10 @@ pub struct EcmascriptModuleContent {
     pub inner_code: Rope,
     pub source_map: Option<Vc<Box<dyn GenerateSourceMap>>>,
     pub is_esm: bool,
+    /// The directives from the module's directive prologue when
+    /// [`EcmascriptOptions::preserve_directives`] is set. They are removed from
+    /// `inner_code` and emitted by the module factory instead.
+    pub directives: Vec<RcStr>,
     // pub refresh: bool,
 }
 
@@ -726,6 +748,7 @@ impl EcmascriptModuleContent {
         source_map: Vc<OptionSourceMap>,
         exports: Vc<EcmascriptExports>,
         async_module_info: Option<Vc<AsyncModuleInfo>>,
+        options: Vc<EcmascriptOptions>,
     ) -> Result<Vc<Self>> {
         let mut code_gens = Vec::new();
         for r in references.await?.iter() {
@@ -783,6 +806,7 @@ impl EcmascriptModuleContent {
             visitors,
             root_visitors,
             source_map,
+            options.await?.preserve_directives,
         )
         .await
     }
@@ -801,6 +825,7 @@ impl EcmascriptModuleContent {
             Vec::new(),
             Vec::new(),
             OptionSourceMap::none(),
+            false,
         )
         .await
     }
@@ -816,6 +841,7 @@ async fn gen_content_with_visitors(
     )>,
     root_visitors: Vec<&dyn VisitorFactory>,
     original_src_map: Vc<OptionSourceMap>,
+    preserve_directives: bool,
 ) -> Result<Vc<EcmascriptModuleContent>> {
     let parsed = parsed.await?;
 
@@ -829,6 +855,11 @@ async fn gen_content_with_visitors(
             ..
         } => {
             let mut program = program.clone();
+            let directives = if preserve_directives {
+                directive_prologue(&program)
+            } else {
+                Vec::new()
+            };
 
             GLOBALS.set(globals, || {
                 if !visitors.is_empty() {
@@ -840,6 +871,9 @@ async fn gen_content_with_visitors(
                 for visitor in root_visitors {
                     program.visit_mut_with(&mut visitor.create());
                 }
+                // Visitors might have hoisted code above the directive prologue, so the
+                // directives are emitted by the module factory instead.
+                remove_directives(&mut program, &directives);
                 program.visit_mut_with(
                     &mut swc_core::ecma::transforms::base::hygiene::hygiene_with_config(
                         swc_core::ecma::transforms::base::hygiene::Config {
@@ -879,6 +913,7 @@ async fn gen_content_with_visitors(
                 inner_code: bytes.into(),
                 source_map: Some(Vc::upcast(srcmap)),
                 is_esm: eval_context.is_esm(specified_module_type),
+                directives,
             }
             .cell())
         }
@@ -895,6 +930,7 @@ async fn gen_content_with_visitors(
             .into(),
             source_map: None,
             is_esm: false,
+            directives: Vec::new(),
         }
         .cell()),
         _ => Ok(EcmascriptModuleContent {
@@ -906,6 +942,7 @@ async fn gen_content_with_visitors(
             .into(),
             source_map: None,
             is_esm: false,
+            directives: Vec::new(),
         }
         .cell()),
     }
@@ -8,7 +8,7 @@ use turbo_tasks::{RcStr, Value, ValueToString, Vc};
 use turbopack_core::{
     chunk::{ChunkableModuleReference, ChunkingContext},
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     resolve::{origin::ResolveOrigin, parse::Request, ModuleResolveResult},
 };
 use turbopack_resolve::ecmascript::{cjs_resolve, try_to_severity};
@@ -58,6 +58,11 @@ impl ModuleReference for CjsAssetReference {
             try_to_severity(self.in_try),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::CjsRequire.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -114,6 +119,11 @@ impl ModuleReference for CjsRequireAssetReference {
             try_to_severity(self.in_try),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::CjsRequire.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -221,6 +231,11 @@ impl ModuleReference for CjsRequireResolveAssetReference {
             try_to_severity(self.in_try),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::CjsRequire.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
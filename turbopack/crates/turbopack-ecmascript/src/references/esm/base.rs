@@ -18,7 +18,7 @@ use turbopack_core::{
         OptionStyledString, StyledString,
     },
     module::Module,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::{EcmaScriptModulesReferenceSubType, ImportWithType},
     resolve::{
         origin::{ResolveOrigin, ResolveOriginExt},
@@ -214,6 +214,11 @@ impl ModuleReference for EsmAssetReference {
 
         Ok(result)
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::EsmImport.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
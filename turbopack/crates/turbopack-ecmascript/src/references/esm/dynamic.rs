@@ -9,7 +9,7 @@ use turbopack_core::{
     chunk::{ChunkableModuleReference, ChunkingContext, ChunkingType, ChunkingTypeOption},
     environment::ChunkLoading,
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::EcmaScriptModulesReferenceSubType,
     resolve::{origin::ResolveOrigin, parse::Request, ModuleResolveResult},
 };
@@ -67,6 +67,11 @@ impl ModuleReference for EsmAsyncAssetReference {
             Some(self.issue_source),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::DynamicImport.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
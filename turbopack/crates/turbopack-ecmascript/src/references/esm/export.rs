@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{hash_map::Entry, BTreeMap, BTreeSet, HashMap, HashSet},
     ops::ControlFlow,
 };
 
@@ -282,6 +282,7 @@ async fn get_all_export_names(
     let exports = exports.await?;
     let mut esm_exports = IndexMap::new();
     let mut dynamic_exporting_modules = Vec::new();
+    let mut ambiguous_exports = HashSet::new();
     esm_exports.extend(exports.exports.keys().cloned().map(|n| (n, module)));
     let star_export_names = exports
         .star_exports
@@ -301,12 +302,27 @@ async fn get_all_export_names(
         .await?;
     for star_export_names in star_export_names {
         let star_export_names = star_export_names.await?;
-        esm_exports.extend(
-            star_export_names
-                .esm_exports
-                .iter()
-                .map(|(k, &v)| (k.clone(), v)),
-        );
+        for (name, &origin) in star_export_names.esm_exports.iter() {
+            // Explicit exports take precedence over star exports, and `default` is never
+            // re-exported by `export *`.
+            if &**name == "default"
+                || exports.exports.contains_key(name)
+                || ambiguous_exports.contains(name)
+            {
+                continue;
+            }
+            match esm_exports.entry(name.clone()) {
+                indexmap::map::Entry::Vacant(entry) => {
+                    entry.insert(origin);
+                }
+                indexmap::map::Entry::Occupied(entry) => {
+                    if !is_same_binding(*entry.get(), origin, name.clone()).await? {
+                        entry.shift_remove();
+                        ambiguous_exports.insert(name.clone());
+                    }
+                }
+            }
+        }
         dynamic_exporting_modules
             .extend(star_export_names.dynamic_exporting_modules.iter().copied());
     }
@@ -318,6 +334,51 @@ async fn get_all_export_names(
     .cell())
 }
 
+/// Checks whether `export_name` exported by `a` and by `b` refers to the same
+/// binding, e.g. because both re-export it from a common module. Star exports
+/// providing the same binding are not ambiguous.
+async fn is_same_binding(
+    a: Vc<Box<dyn EcmascriptChunkPlaceable>>,
+    b: Vc<Box<dyn EcmascriptChunkPlaceable>>,
+    export_name: RcStr,
+) -> Result<bool> {
+    if a == b {
+        return Ok(true);
+    }
+    Ok(binding_origin(a, export_name.clone()).await? == binding_origin(b, export_name).await?)
+}
+
+/// Follows named re-exports (`export { a as b } from "..."`) to the module
+/// declaring the binding.
+async fn binding_origin(
+    module: Vc<Box<dyn EcmascriptChunkPlaceable>>,
+    export_name: RcStr,
+) -> Result<(Vc<Box<dyn EcmascriptChunkPlaceable>>, RcStr)> {
+    let mut module = module;
+    let mut export_name = export_name;
+    let mut visited = HashSet::new();
+    while visited.insert((module, export_name.clone())) {
+        let exports = module.get_exports().await?;
+        let EcmascriptExports::EsmExports(exports) = &*exports else {
+            break;
+        };
+        let exports = exports.await?;
+        let Some(EsmExport::ImportedBinding(reference, name, _)) =
+            exports.exports.get(&export_name)
+        else {
+            break;
+        };
+        let ReferencedAsset::Some(m) =
+            *ReferencedAsset::from_resolve_result(reference.resolve_reference()).await?
+        else {
+            break;
+        };
+        module = m;
+        export_name = name.clone();
+    }
+    Ok((module, export_name))
+}
+
 #[turbo_tasks::value]
 pub struct ExpandStarResult {
     pub star_exports: Vec<RcStr>,
@@ -427,6 +488,9 @@ pub struct ExpandedExports {
     pub exports: BTreeMap<RcStr, EsmExport>,
     /// Modules we couldn't analyse all exports of.
     pub dynamic_exports: Vec<Vc<Box<dyn EcmascriptChunkPlaceable>>>,
+    /// Names provided with different bindings by multiple `star_exports`.
+    /// These are omitted from `exports`, following ESM semantics.
+    pub ambiguous_exports: Vec<RcStr>,
 }
 
 #[turbo_tasks::value_impl]
@@ -435,6 +499,8 @@ impl EsmExports {
     pub async fn expand_exports(&self) -> Result<Vc<ExpandedExports>> {
         let mut exports: BTreeMap<RcStr, EsmExport> = self.exports.clone();
         let mut dynamic_exports = vec![];
+        let mut star_export_origins = HashMap::new();
+        let mut ambiguous_exports = BTreeSet::new();
 
         for esm_ref in self.star_exports.iter() {
             // TODO(PACK-2176): we probably need to handle re-exporting from external
@@ -446,13 +512,32 @@ impl EsmExports {
             };
 
             let export_info = expand_star_exports(*asset).await?;
+            let export_names = get_all_export_names(*asset).await?;
 
             for export in &export_info.star_exports {
-                if !exports.contains_key(export) {
-                    exports.insert(
-                        export.clone(),
-                        EsmExport::ImportedBinding(Vc::upcast(*esm_ref), export.clone(), false),
-                    );
+                if self.exports.contains_key(export) || ambiguous_exports.contains(export) {
+                    continue;
+                }
+                // Names that are already ambiguous within the star exported module are
+                // not exported by it.
+                let Some(&origin) = export_names.esm_exports.get(export) else {
+                    continue;
+                };
+                match star_export_origins.entry(export.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(origin);
+                        exports.insert(
+                            export.clone(),
+                            EsmExport::ImportedBinding(Vc::upcast(*esm_ref), export.clone(), false),
+                        );
+                    }
+                    Entry::Occupied(entry) => {
+                        if !is_same_binding(*entry.get(), origin, export.clone()).await? {
+                            entry.remove();
+                            exports.remove(export);
+                            ambiguous_exports.insert(export.clone());
+                        }
+                    }
                 }
             }
 
@@ -464,11 +549,36 @@ impl EsmExports {
         Ok(ExpandedExports {
             exports,
             dynamic_exports,
+            ambiguous_exports: ambiguous_exports.into_iter().collect(),
         }
         .cell())
     }
 }
 
+/// Emits a warning for every name that is omitted from `exports` because
+/// multiple `export *` provide different bindings for it.
+pub async fn emit_ambiguous_star_exports_issues(
+    exports: Vc<EsmExports>,
+    source_ident: Vc<AssetIdent>,
+) -> Result<()> {
+    if exports.await?.star_exports.len() < 2 {
+        return Ok(());
+    }
+    let expanded = exports.expand_exports().await?;
+    for name in &expanded.ambiguous_exports {
+        emit_star_exports_issue(
+            source_ident,
+            format!(
+                "The export {name} is provided by multiple modules used with export * and is \
+                 omitted as it is ambiguous.\nExport it explicitly (`export {{ {name} }} from \
+                 \"...\";`) to choose which module it should be exported from."
+            )
+            .into(),
+        );
+    }
+    Ok(())
+}
+
 #[turbo_tasks::value_impl]
 impl CodeGenerateable for EsmExports {
     #[turbo_tasks::function]
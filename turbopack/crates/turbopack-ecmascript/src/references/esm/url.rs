@@ -11,7 +11,7 @@ use turbopack_core::{
     },
     environment::Rendering,
     issue::IssueSource,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::{ReferenceType, UrlReferenceSubType},
     resolve::{
         origin::ResolveOrigin, parse::Request, url_resolve, ExternalType, ModuleResolveResult,
@@ -99,6 +99,11 @@ impl ModuleReference for UrlAssetReference {
             try_to_severity(self.in_try),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::Url.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -92,6 +92,7 @@ impl CachedExternalModule {
             inner_code: code.build(),
             source_map: None,
             is_esm: self.external_type != CachedExternalType::CommonJs,
+            directives: Vec::new(),
         }
         .cell())
     }
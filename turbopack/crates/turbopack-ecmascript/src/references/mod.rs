@@ -475,7 +475,13 @@ pub(crate) async fn analyse_ecmascript_module_internal(
         };
     }
 
-    special_cases(&path.await?.path, &mut analysis);
+    special_cases(
+        &path.await?.path,
+        source,
+        options.special_cases,
+        &mut analysis,
+    )
+    .await?;
 
     let parsed = parsed.await?;
 
@@ -527,6 +533,25 @@ pub(crate) async fn analyse_ecmascript_module_internal(
             ])
             .or_insert("function".into());
 
+        // Only available globals are folded, since missing ones might still be
+        // polyfilled at runtime. `runtime_capabilities` is a task of the environment,
+        // so the Node.js version is resolved once and not per module.
+        let capabilities = compile_time_info.environment.runtime_capabilities().await?;
+        for (name, available) in [
+            ("fetch", capabilities.fetch),
+            ("Buffer", capabilities.buffer),
+            ("WebSocket", capabilities.web_socket),
+        ] {
+            if available {
+                free_var_references
+                    .entry(vec![
+                        DefineableNameSegment::Name(name.into()),
+                        DefineableNameSegment::TypeOf,
+                    ])
+                    .or_insert("function".into());
+            }
+        }
+
         CompileTimeInfo {
             environment: compile_time_info.environment,
             defines: compile_time_info.defines,
@@ -1119,43 +1144,58 @@ pub(crate) async fn analyse_ecmascript_module_internal(
                         continue;
                     }
                 }
-                let mut obj = analysis_state
-                    .link_value(obj, ImportOverrides::empty_ref())
-                    .await?;
                 let prop = analysis_state
                     .link_value(prop, ImportOverrides::empty_ref())
                     .await?;
+                let is_array_callback =
+                    !new && matches!(prop.as_str(), Some("map" | "forEach" | "filter"));
+                // The result of `filter` isn't known exactly, but a callback on it sees a subset
+                // of the items of the filtered array, e.g. in `[...].filter(fn).forEach(fn)`.
+                let filtered = if is_array_callback {
+                    filtered_array(&obj).cloned()
+                } else {
+                    None
+                };
+                let mut obj = analysis_state
+                    .link_value(obj, ImportOverrides::empty_ref())
+                    .await?;
+                let mut filtered = match filtered {
+                    Some(filtered) => Some(
+                        analysis_state
+                            .link_value(filtered, ImportOverrides::empty_ref())
+                            .await?,
+                    ),
+                    None => None,
+                };
 
-                if !new {
+                if is_array_callback {
                     if let JsValue::Array {
-                        items: ref mut values,
+                        items: values,
                         mutable,
                         ..
-                    } = obj
+                    } = filtered.as_mut().unwrap_or(&mut obj)
                     {
-                        if matches!(prop.as_str(), Some("map" | "forEach" | "filter")) {
-                            if let [EffectArg::Closure(value, block)] = &mut args[..] {
-                                *value = analysis_state
-                                    .link_value(take(value), ImportOverrides::empty_ref())
-                                    .await?;
-                                if let JsValue::Function(_, func_ident, _) = value {
-                                    let mut closure_arg = JsValue::alternatives(take(values));
-                                    if mutable {
-                                        closure_arg.add_unknown_mutations(true);
-                                    }
-                                    analysis_state
-                                        .fun_args_values
-                                        .get_mut()
-                                        .insert(*func_ident, vec![closure_arg]);
-                                    queue_stack.get_mut().push(Action::LeaveScope(*func_ident));
-                                    queue_stack.get_mut().extend(
-                                        take(&mut block.effects)
-                                            .into_iter()
-                                            .map(Action::Effect)
-                                            .rev(),
-                                    );
-                                    continue;
+                        if let [EffectArg::Closure(value, block)] = &mut args[..] {
+                            *value = analysis_state
+                                .link_value(take(value), ImportOverrides::empty_ref())
+                                .await?;
+                            if let JsValue::Function(_, func_ident, _) = value {
+                                let mut closure_arg = JsValue::alternatives(take(values));
+                                if *mutable {
+                                    closure_arg.add_unknown_mutations(true);
                                 }
+                                analysis_state
+                                    .fun_args_values
+                                    .get_mut()
+                                    .insert(*func_ident, vec![closure_arg]);
+                                queue_stack.get_mut().push(Action::LeaveScope(*func_ident));
+                                queue_stack.get_mut().extend(
+                                    take(&mut block.effects)
+                                        .into_iter()
+                                        .map(Action::Effect)
+                                        .rev(),
+                                );
+                                continue;
                             }
                         }
                     }
@@ -2717,6 +2757,18 @@ impl<'a> ModuleReferencesVisitor<'a> {
     }
 }
 
+/// Returns the array that `value` filters, following chains like
+/// `array.filter(a).filter(b)`.
+fn filtered_array(value: &JsValue) -> Option<&JsValue> {
+    let JsValue::MemberCall(_, box obj, box prop, _) = value else {
+        return None;
+    };
+    if prop.as_str() != Some("filter") {
+        return None;
+    }
+    Some(filtered_array(obj).unwrap_or(obj))
+}
+
 fn as_parent_path(ast_path: &AstNodePath<AstParentNodeRef<'_>>) -> Vec<AstParentKind> {
     ast_path.iter().map(|n| n.kind()).collect()
 }
@@ -66,9 +66,14 @@ pub(crate) enum PatternMapping {
     Single(SinglePatternMapping),
     /// Variable request that can map to different modules at runtime.
     ///
+    /// The map only contains the requests the pattern can match, e.g. both
+    /// alternatives of a conditional request, so unrelated modules are not
+    /// included.
+    ///
     /// ### Example
     /// ```js
     /// require(`./images/${name}.png`)
+    /// require(condition ? "./a.js" : "./b.js")
     /// ```
     Map(IndexMap<String, SinglePatternMapping>),
 }
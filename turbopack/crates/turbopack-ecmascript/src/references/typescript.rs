@@ -4,9 +4,13 @@ use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
     context::AssetContext,
     file_source::FileSource,
-    reference::ModuleReference,
+    reference::{AssetReferenceType, ModuleReference},
     reference_type::{ReferenceType, TypeScriptReferenceSubType},
-    resolve::{origin::ResolveOrigin, parse::Request, ModuleResolveResult},
+    resolve::{
+        origin::{PlainResolveOrigin, ResolveOrigin},
+        parse::Request,
+        ModuleResolveResult,
+    },
 };
 use turbopack_resolve::typescript::type_resolve;
 
@@ -31,8 +35,11 @@ impl TsConfigReference {
 impl ModuleReference for TsConfigReference {
     #[turbo_tasks::function]
     fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
+        // Requests in a tsconfig (like `ts-node.require` or `compilerOptions.types`)
+        // are relative to the tsconfig, not to the module referencing it.
+        let origin = PlainResolveOrigin::new(self.origin.asset_context(), self.tsconfig);
         ModuleResolveResult::module(Vc::upcast(TsConfigModuleAsset::new(
-            self.origin,
+            Vc::upcast(origin),
             Vc::upcast(FileSource::new(self.tsconfig)),
         )))
         .into()
@@ -92,6 +99,11 @@ impl ModuleReference for TsReferencePathAssetReference {
             },
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::TypeOnly.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -133,6 +145,11 @@ impl ModuleReference for TsReferenceTypeAssetReference {
             ),
         )
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::TypeOnly.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -59,6 +59,7 @@ impl EcmascriptChunkItem for EcmascriptModuleLocalsChunkItem {
             analyze_result.source_map,
             exports,
             async_module_info,
+            original_module.await?.options,
         );
 
         Ok(EcmascriptChunkItemContent::new(
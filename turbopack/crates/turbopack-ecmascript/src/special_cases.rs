@@ -1,3 +1,71 @@
-use crate::references::AnalyzeEcmascriptModuleResultBuilder;
+use anyhow::Result;
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_fs::glob::Glob;
+use turbopack_core::{resolve::pattern::Pattern, source::Source};
 
-pub(crate) fn special_cases(_path: &str, _references: &mut AnalyzeEcmascriptModuleResultBuilder) {}
+use crate::references::{raw::FileSourceReference, AnalyzeEcmascriptModuleResultBuilder};
+
+/// A module special case registered by an integration.
+///
+/// Every module whose path matches `path` gets a reference to each of
+/// `references`, resolved relative to the module. This allows to include
+/// files a package loads in ways the analysis can't follow, e.g. data files
+/// read with a computed path.
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Clone)]
+pub struct SpecialCase {
+    pub path: Vc<Glob>,
+    pub references: Vec<RcStr>,
+}
+
+#[turbo_tasks::value_impl]
+impl SpecialCase {
+    #[turbo_tasks::function]
+    pub fn new(path: Vc<Glob>, references: Vec<RcStr>) -> Vc<Self> {
+        SpecialCase { path, references }.cell()
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct SpecialCases(Vec<Vc<SpecialCase>>);
+
+#[turbo_tasks::value_impl]
+impl SpecialCases {
+    #[turbo_tasks::function]
+    pub fn empty() -> Vc<Self> {
+        Vc::cell(Vec::new())
+    }
+
+    /// Returns a new list of special cases which additionally contains
+    /// `special_case`.
+    #[turbo_tasks::function]
+    pub async fn register(self: Vc<Self>, special_case: Vc<SpecialCase>) -> Result<Vc<Self>> {
+        let mut special_cases = self.await?.clone_value();
+        special_cases.push(special_case);
+        Ok(Vc::cell(special_cases))
+    }
+}
+
+pub(crate) async fn special_cases(
+    path: &str,
+    source: Vc<Box<dyn Source>>,
+    special_cases: Option<Vc<SpecialCases>>,
+    references: &mut AnalyzeEcmascriptModuleResultBuilder,
+) -> Result<()> {
+    let Some(special_cases) = special_cases else {
+        return Ok(());
+    };
+    for special_case in special_cases.await?.iter() {
+        let special_case = special_case.await?;
+        if !special_case.path.await?.execute(path) {
+            continue;
+        }
+        for reference in &special_case.references {
+            references.add_reference(FileSourceReference::new(
+                source,
+                Pattern::new(Pattern::Constant(reference.clone())),
+            ));
+        }
+    }
+    Ok(())
+}
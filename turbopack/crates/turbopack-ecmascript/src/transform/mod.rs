@@ -1,13 +1,16 @@
 use std::{fmt::Debug, hash::Hash, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use swc_core::{
     atoms::JsWord,
     base::SwcComments,
-    common::{chain, collections::AHashMap, comments::Comments, util::take::Take, Mark, SourceMap},
+    common::{
+        chain, collections::AHashMap, comments::Comments, util::take::Take, Mark, SourceMap,
+        Spanned,
+    },
     ecma::{
-        ast::{Module, ModuleItem, Program, Script},
+        ast::{Expr, ExprStmt, Lit, Module, ModuleItem, Program, Script, Stmt, Str},
         preset_env::{self, Targets},
         transforms::{
             base::{feature::FeatureFlag, helpers::inject_helpers, Assumptions},
@@ -118,6 +121,63 @@ impl EcmascriptInputTransforms {
     }
 }
 
+/// Builds a list of [EcmascriptInputTransforms] and checks that the
+/// transforms that depend on each other are in a valid order:
+///
+/// 1. decorators, which need the type annotations for their metadata
+/// 2. TypeScript, which strips the types
+/// 3. React, which compiles JSX that the TypeScript transform keeps intact
+/// 4. preset-env, which downlevels the output of all of the above
+///
+/// Each of them can be added once. Other transforms can be placed anywhere.
+#[derive(Default)]
+pub struct EcmascriptInputTransformsBuilder {
+    transforms: Vec<EcmascriptInputTransform>,
+}
+
+impl EcmascriptInputTransformsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(mut self, transform: EcmascriptInputTransform) -> Self {
+        self.transforms.push(transform);
+        self
+    }
+
+    pub fn extend(
+        mut self,
+        transforms: impl IntoIterator<Item = EcmascriptInputTransform>,
+    ) -> Self {
+        self.transforms.extend(transforms);
+        self
+    }
+
+    pub fn build(self) -> Result<Vc<EcmascriptInputTransforms>> {
+        check_transform_order(&self.transforms)?;
+        Ok(Vc::cell(self.transforms))
+    }
+}
+
+fn check_transform_order(transforms: &[EcmascriptInputTransform]) -> Result<()> {
+    let mut previous: Option<(u8, &'static str)> = None;
+    for transform in transforms {
+        let Some((rank, name)) = transform.order() else {
+            continue;
+        };
+        if let Some((previous_rank, previous_name)) = previous {
+            if rank == previous_rank {
+                bail!("the {name} transform must only be added once");
+            }
+            if rank < previous_rank {
+                bail!("the {name} transform must run before the {previous_name} transform");
+            }
+        }
+        previous = Some((rank, name));
+    }
+    Ok(())
+}
+
 pub struct TransformContext<'a> {
     pub comments: &'a SwcComments,
     pub top_level_mark: Mark,
@@ -130,6 +190,20 @@ pub struct TransformContext<'a> {
 }
 
 impl EcmascriptInputTransform {
+    /// The position and name of transforms that must run in a fixed order,
+    /// see [EcmascriptInputTransformsBuilder].
+    fn order(&self) -> Option<(u8, &'static str)> {
+        match self {
+            EcmascriptInputTransform::Decorators { .. } => Some((0, "decorators")),
+            EcmascriptInputTransform::TypeScript { .. } => Some((1, "TypeScript")),
+            EcmascriptInputTransform::React { .. } => Some((2, "React")),
+            EcmascriptInputTransform::PresetEnv(_) => Some((3, "preset-env")),
+            EcmascriptInputTransform::CommonJs
+            | EcmascriptInputTransform::Plugin(_)
+            | EcmascriptInputTransform::GlobalTypeofs { .. } => None,
+        }
+    }
+
     pub async fn apply(&self, program: &mut Program, ctx: &TransformContext<'_>) -> Result<()> {
         let &TransformContext {
             comments,
@@ -320,6 +394,73 @@ pub fn remove_shebang(program: &mut Program) {
     }
 }
 
+fn as_directive(stmt: &Stmt) -> Option<&Str> {
+    match stmt {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(directive)) => Some(directive),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the directives (e.g. `"use strict"`) in the directive prologue of
+/// the program, in source order.
+pub fn directive_prologue(program: &Program) -> Vec<RcStr> {
+    match program {
+        Program::Module(m) => m
+            .body
+            .iter()
+            .map_while(|item| match item {
+                ModuleItem::Stmt(stmt) => as_directive(stmt),
+                _ => None,
+            })
+            .map(|directive| RcStr::from(&*directive.value))
+            .collect(),
+        Program::Script(s) => s
+            .body
+            .iter()
+            .map_while(as_directive)
+            .map(|directive| RcStr::from(&*directive.value))
+            .collect(),
+    }
+}
+
+/// Removes the given directives from the directive prologue of the program.
+///
+/// Code generation might have inserted statements in front of the directive
+/// prologue. These don't have a span, so they are skipped. The prologue ends
+/// at the first statement from the source that isn't one of the directives;
+/// string statements after it are left alone.
+pub fn remove_directives(program: &mut Program, directives: &[RcStr]) {
+    if directives.is_empty() {
+        return;
+    }
+    let mut remaining = directives.iter().peekable();
+    let mut in_prologue = true;
+    let mut is_directive = |stmt: &Stmt| {
+        if !in_prologue || stmt.span().is_dummy() {
+            return false;
+        }
+        match (as_directive(stmt), remaining.peek()) {
+            (Some(directive), Some(expected)) if &*directive.value == expected.as_str() => {
+                remaining.next();
+                true
+            }
+            _ => {
+                in_prologue = false;
+                false
+            }
+        }
+    };
+    match program {
+        Program::Module(m) => m
+            .body
+            .retain(|item| !matches!(item, ModuleItem::Stmt(stmt) if is_directive(stmt))),
+        Program::Script(s) => s.body.retain(|stmt| !is_directive(stmt)),
+    }
+}
+
 #[turbo_tasks::value(shared)]
 pub struct UnsupportedServerActionIssue {
     pub file_path: Vc<FileSystemPath>,
@@ -350,3 +491,99 @@ impl Issue for UnsupportedServerActionIssue {
         IssueStage::Transform.cell()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{
+        common::{BytePos, Span, DUMMY_SP},
+        ecma::ast::{Expr, ExprStmt, Lit, Program, Script, Stmt},
+    };
+
+    use super::{check_transform_order, remove_directives, EcmascriptInputTransform};
+
+    fn decorators() -> EcmascriptInputTransform {
+        EcmascriptInputTransform::Decorators {
+            is_legacy: true,
+            is_ecma: false,
+            emit_decorators_metadata: true,
+            use_define_for_class_fields: false,
+        }
+    }
+
+    fn typescript() -> EcmascriptInputTransform {
+        EcmascriptInputTransform::TypeScript {
+            use_define_for_class_fields: false,
+        }
+    }
+
+    #[test]
+    fn valid_transform_order() {
+        check_transform_order(&[
+            decorators(),
+            EcmascriptInputTransform::CommonJs,
+            typescript(),
+            EcmascriptInputTransform::GlobalTypeofs {
+                window_value: "object".to_string(),
+            },
+        ])
+        .unwrap();
+        check_transform_order(&[typescript()]).unwrap();
+        check_transform_order(&[]).unwrap();
+    }
+
+    #[test]
+    fn invalid_transform_order() {
+        let error = check_transform_order(&[typescript(), decorators()]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the decorators transform must run before the TypeScript transform"
+        );
+
+        let error = check_transform_order(&[typescript(), typescript()]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the TypeScript transform must only be added once"
+        );
+    }
+
+    fn string_stmt(value: &str, span: Span) -> Stmt {
+        Stmt::Expr(ExprStmt {
+            span,
+            expr: Box::new(Expr::Lit(Lit::Str(value.into()))),
+        })
+    }
+
+    fn source_span(lo: u32) -> Span {
+        Span::new(BytePos(lo), BytePos(lo + 1))
+    }
+
+    #[test]
+    fn remove_directives_only_touches_the_prologue() {
+        let mut program = Program::Script(Script {
+            span: DUMMY_SP,
+            body: vec![
+                // inserted by code generation
+                string_stmt("hoisted", DUMMY_SP),
+                string_stmt("use strict", source_span(1)),
+                string_stmt("use client", source_span(2)),
+                string_stmt("not a directive", source_span(3)),
+                string_stmt("use strict", source_span(4)),
+            ],
+            shebang: None,
+        });
+
+        remove_directives(&mut program, &["use strict".into(), "use client".into()]);
+
+        let Program::Script(script) = program else {
+            unreachable!()
+        };
+        assert_eq!(
+            script.body,
+            vec![
+                string_stmt("hoisted", DUMMY_SP),
+                string_stmt("not a directive", source_span(3)),
+                string_stmt("use strict", source_span(4)),
+            ]
+        );
+    }
+}
@@ -57,6 +57,7 @@ impl EcmascriptChunkItem for EcmascriptModulePartChunkItem {
             analyze.source_map,
             analyze.exports,
             async_module_info,
+            module.full_module.await?.options,
         );
 
         Ok(EcmascriptChunkItemContent::new(
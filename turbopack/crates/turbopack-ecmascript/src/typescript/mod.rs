@@ -8,7 +8,7 @@ use turbopack_core::{
     issue::IssueSeverity,
     module::Module,
     raw_module::RawModule,
-    reference::{ModuleReference, ModuleReferences},
+    reference::{AssetReferenceType, ModuleReference, ModuleReferences},
     reference_type::{CommonJsReferenceSubType, ReferenceType},
     resolve::{
         origin::{ResolveOrigin, ResolveOriginExt},
@@ -290,6 +290,11 @@ impl ModuleReference for TsConfigTypesReference {
     fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
         type_resolve(self.origin, self.request)
     }
+
+    #[turbo_tasks::function]
+    fn kind(&self) -> Vc<AssetReferenceType> {
+        AssetReferenceType::TypeOnly.cell()
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -13,7 +13,7 @@ use turbo_tasks_bytes::stream::SingleValue;
 use turbo_tasks_env::ProcessEnv;
 use turbo_tasks_fs::{
     glob::Glob, json::parse_json_with_source_context, DirectoryEntry, File, FileContent,
-    FileSystemPath, ReadGlobResult,
+    FileSystemPath, ReadGlobLimits, ReadGlobResult,
 };
 use turbopack_core::{
     asset::{Asset, AssetContent},
@@ -21,7 +21,10 @@ use turbopack_core::{
     context::{AssetContext, ProcessResult},
     file_source::FileSource,
     ident::AssetIdent,
-    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    issue::{
+        read_glob::read_glob_or_emit_issue, Issue, IssueExt, IssueSeverity, IssueStage,
+        OptionStyledString, StyledString,
+    },
     module::Module,
     reference_type::{InnerAssets, ReferenceType},
     resolve::{
@@ -461,7 +464,13 @@ impl EvaluateContext for WebpackLoaderContext {
                 // TODO We might miss some changes that happened during execution
                 // Read dependencies to make them a dependencies of this task. This task will
                 // execute again when they change.
-                dir_dependency(self.cwd.join(path).read_glob(Glob::new(glob), false)).await?;
+                dir_dependency(read_glob_or_emit_issue(
+                    self.cwd.join(path),
+                    Glob::new(glob),
+                    false,
+                    ReadGlobLimits::default_limits(),
+                ))
+                .await?;
             }
             InfoMessage::EmittedError { error, severity } => {
                 EvaluateEmittedErrorIssue {
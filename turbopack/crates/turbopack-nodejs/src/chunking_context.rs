@@ -7,8 +7,9 @@ use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
     chunk::{
         availability_info::AvailabilityInfo,
+        base_path_with_trailing_slash,
         chunk_group::{make_chunk_group, MakeChunkGroupResult},
-        module_id_strategies::{DevModuleIdStrategy, ModuleIdStrategy},
+        module_id_strategies::{DevModuleIdStrategy, GlobalModuleIdStrategy, ModuleIdStrategy},
         Chunk, ChunkGroupResult, ChunkItem, ChunkableModule, ChunkingContext,
         EntryChunkGroupResult, EvaluatableAssets, MinifyType, ModuleId,
     },
@@ -44,6 +45,11 @@ impl NodeJsChunkingContextBuilder {
         self
     }
 
+    pub fn reference_chunk_source_maps(mut self, source_maps: bool) -> Self {
+        self.chunking_context.reference_chunk_source_maps = source_maps;
+        self
+    }
+
     pub fn runtime_type(mut self, runtime_type: RuntimeType) -> Self {
         self.chunking_context.runtime_type = runtime_type;
         self
@@ -88,6 +94,8 @@ pub struct NodeJsChunkingContext {
     runtime_type: RuntimeType,
     /// Whether to minify resulting chunks
     minify_type: MinifyType,
+    /// Chunks reference source maps assets
+    reference_chunk_source_maps: bool,
     /// Whether to use manifest chunks for lazy compilation
     manifest_chunks: bool,
     /// The strategy to use for generating module ids
@@ -116,11 +124,57 @@ impl NodeJsChunkingContext {
                 environment,
                 runtime_type,
                 minify_type: MinifyType::NoMinify,
+                reference_chunk_source_maps: true,
                 manifest_chunks: false,
                 module_id_strategy: Vc::upcast(DevModuleIdStrategy::new()),
             },
         }
     }
+
+    /// Creates a chunking context builder with defaults for development:
+    /// readable module ids and source maps, without minification.
+    pub fn development(
+        context_path: Vc<FileSystemPath>,
+        output_root: Vc<FileSystemPath>,
+        client_root: Vc<FileSystemPath>,
+        chunk_root_path: Vc<FileSystemPath>,
+        asset_root_path: Vc<FileSystemPath>,
+        environment: Vc<Environment>,
+    ) -> NodeJsChunkingContextBuilder {
+        Self::builder(
+            context_path,
+            output_root,
+            client_root,
+            chunk_root_path,
+            asset_root_path,
+            environment,
+            RuntimeType::Development,
+        )
+    }
+
+    /// Creates a chunking context builder with defaults for production:
+    /// hashed module ids and minification, without source map references.
+    pub fn production(
+        context_path: Vc<FileSystemPath>,
+        output_root: Vc<FileSystemPath>,
+        client_root: Vc<FileSystemPath>,
+        chunk_root_path: Vc<FileSystemPath>,
+        asset_root_path: Vc<FileSystemPath>,
+        environment: Vc<Environment>,
+    ) -> NodeJsChunkingContextBuilder {
+        Self::builder(
+            context_path,
+            output_root,
+            client_root,
+            chunk_root_path,
+            asset_root_path,
+            environment,
+            RuntimeType::Production,
+        )
+        .minify_type(MinifyType::Minify)
+        .module_id_strategy(Vc::upcast(GlobalModuleIdStrategy::hashed()))
+        .reference_chunk_source_maps(false)
+    }
 }
 
 impl NodeJsChunkingContext {
@@ -201,14 +255,13 @@ impl ChunkingContext for NodeJsChunkingContext {
             .strip_prefix(&format!("{}/", this.client_root.await?.path))
             .context("expected client root to contain asset path")?;
 
+        let asset_prefix = this.asset_prefix.await?;
+        let asset_prefix = asset_prefix.as_deref().unwrap_or("/");
+
         Ok(Vc::cell(
             format!(
                 "{}{}",
-                this.asset_prefix
-                    .await?
-                    .as_ref()
-                    .map(|s| s.clone())
-                    .unwrap_or_else(|| "/".into()),
+                base_path_with_trailing_slash(asset_prefix),
                 asset_path
             )
             .into(),
@@ -228,7 +281,7 @@ impl ChunkingContext for NodeJsChunkingContext {
 
     #[turbo_tasks::function]
     fn reference_chunk_source_maps(&self, _chunk: Vc<Box<dyn OutputAsset>>) -> Vc<bool> {
-        Vc::cell(true)
+        Vc::cell(self.reference_chunk_source_maps)
     }
 
     #[turbo_tasks::function]
@@ -6,7 +6,7 @@ use turbo_tasks::{RcStr, ValueToString, Vc};
 use turbo_tasks_fs::{File, FileSystem};
 use turbopack_core::{
     asset::{Asset, AssetContent},
-    chunk::ChunkingContext,
+    chunk::{base_path_with_trailing_slash, ChunkingContext},
     code_builder::{Code, CodeBuilder},
     ident::AssetIdent,
     output::{OutputAsset, OutputAssets},
@@ -50,7 +50,7 @@ impl EcmascriptBuildNodeRuntimeChunk {
         let mut code = CodeBuilder::default();
         let output_root = output_root.to_string();
         let asset_prefix = this.chunking_context.asset_prefix().await?;
-        let asset_prefix = asset_prefix.as_deref().unwrap_or("/");
+        let asset_prefix = base_path_with_trailing_slash(asset_prefix.as_deref().unwrap_or("/"));
 
         writedoc!(
             code,
@@ -61,7 +61,7 @@ impl EcmascriptBuildNodeRuntimeChunk {
             "#,
             StringifyJs(runtime_public_path),
             StringifyJs(output_root.as_str()),
-            StringifyJs(asset_prefix),
+            StringifyJs(&asset_prefix),
         )?;
 
         match this.chunking_context.await?.runtime_type() {
@@ -13,7 +13,7 @@ use turbopack_core::{
     file_source::FileSource,
     raw_module::RawModule,
     reference::ModuleReference,
-    resolve::{pattern::Pattern, resolve_raw, ModuleResolveResult, RequestKey, ResolveResultItem},
+    resolve::{pattern::Pattern, resolve_raw, ModuleResolveResult, RequestKey},
     source::Source,
     target::{CompileTarget, Platform},
 };
@@ -281,9 +281,7 @@ pub async fn resolve_node_gyp_build_files(
                             true,
                         )
                         .await?;
-                        if let Some((_, ResolveResultItem::Source(source))) =
-                            resolved_prebuilt_file.primary.first()
-                        {
+                        if let Some(source) = resolved_prebuilt_file.first_source_ref() {
                             resolved.insert(
                                 format!("build/Release/{name}.node").into(),
                                 source.resolve().await?,
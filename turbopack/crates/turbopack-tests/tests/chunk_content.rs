@@ -0,0 +1,497 @@
+#![cfg(test)]
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use anyhow::{bail, Result};
+use turbo_tasks::{TryJoinIterExt, TurboTasks, Value, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileContent, FileSystem, FileSystemPath};
+use turbo_tasks_memory::MemoryBackend;
+use turbopack::{module_options::ModuleOptionsContext, ModuleAssetContext};
+use turbopack_browser::BrowserChunkingContext;
+use turbopack_core::{
+    asset::Asset,
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkingContext, ChunkingContextExt, EvaluatableAsset,
+        EvaluatableAssets, MinifyType,
+    },
+    compile_time_info::CompileTimeInfo,
+    context::AssetContext,
+    environment::{BrowserEnvironment, Environment, ExecutionEnvironment, NodeJsEnvironment},
+    file_source::FileSource,
+    module::Module,
+    output::{OutputAsset, OutputAssets},
+    reference_type::{EntryReferenceSubType, ReferenceType},
+};
+use turbopack_nodejs::NodeJsChunkingContext;
+use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+
+fn register() {
+    turbo_tasks::register();
+    turbo_tasks_fs::register();
+    turbopack::register();
+    turbopack_browser::register();
+    turbopack_nodejs::register();
+    turbopack_ecmascript_runtime::register();
+}
+
+/// Returns the root of the fixture directory `tests/chunk_content/<name>`.
+fn fixture_root(name: &str) -> Vc<FileSystemPath> {
+    let fs = DiskFileSystem::new(
+        "fixture".into(),
+        format!("{}/tests/chunk_content/{name}", env!("CARGO_MANIFEST_DIR")).into(),
+        vec![],
+    );
+    Vc::upcast::<Box<dyn FileSystem>>(fs).root()
+}
+
+fn browser_environment() -> Vc<Environment> {
+    Environment::new(Value::new(ExecutionEnvironment::Browser(
+        BrowserEnvironment {
+            dom: true,
+            web_worker: false,
+            service_worker: false,
+            browserslist_query: "last 1 Chrome versions".into(),
+        }
+        .into(),
+    )))
+}
+
+fn asset_context(environment: Vc<Environment>) -> Vc<Box<dyn AssetContext>> {
+    Vc::upcast(ModuleAssetContext::new(
+        Default::default(),
+        CompileTimeInfo::new(environment),
+        ModuleOptionsContext {
+            preset_env_versions: Some(environment),
+            ..Default::default()
+        }
+        .cell(),
+        ResolveOptionsContext::default().cell(),
+        Vc::cell("test".into()),
+    ))
+}
+
+fn chunking_context(
+    root: Vc<FileSystemPath>,
+    output_root: Vc<FileSystemPath>,
+    chunk_root: Vc<FileSystemPath>,
+    environment: Vc<Environment>,
+    minify_type: MinifyType,
+) -> Vc<Box<dyn ChunkingContext>> {
+    Vc::upcast(
+        BrowserChunkingContext::builder(
+            root,
+            output_root,
+            output_root,
+            chunk_root,
+            output_root.join("static".into()),
+            environment,
+            Default::default(),
+        )
+        .minify_type(minify_type)
+        .build(),
+    )
+}
+
+/// Returns the chunks of the evaluated chunk group for the entry at `path`.
+async fn entry_chunks(
+    asset_context: Vc<Box<dyn AssetContext>>,
+    chunking_context: Vc<Box<dyn ChunkingContext>>,
+    path: Vc<FileSystemPath>,
+) -> Result<Vc<OutputAssets>> {
+    let entry = asset_context
+        .process(
+            Vc::upcast(FileSource::new(path)),
+            Value::new(ReferenceType::Entry(EntryReferenceSubType::Undefined)),
+        )
+        .module();
+    let Some(entry) = Vc::try_resolve_sidecast::<Box<dyn EvaluatableAsset>>(entry).await? else {
+        bail!("{} is not evaluatable", path.await?.path);
+    };
+    Ok(chunking_context.evaluated_chunk_group_assets(
+        entry.ident(),
+        EvaluatableAssets::one(entry),
+        Value::new(AvailabilityInfo::Root),
+    ))
+}
+
+/// Builds `index.js` of the fixture `name` for the browser and returns the
+/// contents of all emitted JavaScript files, keyed by their path relative to
+/// the output directory. Source maps are skipped since they embed the original
+/// sources.
+async fn build(name: &str, minify_type: MinifyType) -> Result<BTreeMap<String, String>> {
+    let root = fixture_root(name);
+    let environment = browser_environment();
+    let output_root = root.join("output".into());
+    let chunking_context = chunking_context(
+        root,
+        output_root,
+        output_root.join("chunks".into()),
+        environment,
+        minify_type,
+    );
+    let chunks = entry_chunks(
+        asset_context(environment),
+        chunking_context,
+        root.join("index.js".into()),
+    )
+    .await?;
+
+    js_files(output_root, chunks).await
+}
+
+/// Returns the contents of all JavaScript files inside `output_root` that are
+/// reachable from `assets`, keyed by their path relative to `output_root`.
+async fn js_files(
+    output_root: Vc<FileSystemPath>,
+    assets: Vc<OutputAssets>,
+) -> Result<BTreeMap<String, String>> {
+    let output_root = output_root.await?;
+    let mut seen = HashSet::new();
+    let mut files = BTreeMap::new();
+    let mut queue: VecDeque<_> = assets.await?.iter().copied().collect();
+    while let Some(asset) = queue.pop_front() {
+        let path = asset.ident().path().resolve().await?;
+        if !seen.insert(path) {
+            continue;
+        }
+        if let Some(relative) = output_root
+            .get_path_to(&*path.await?)
+            .filter(|relative| relative.ends_with(".js"))
+        {
+            let content = match &*asset.content().file_content().await? {
+                FileContent::Content(file) => file.content().to_str()?.into_owned(),
+                FileContent::NotFound => bail!("{relative} has no content"),
+            };
+            files.insert(relative.to_string(), content);
+        }
+        queue.extend(
+            asset
+                .references()
+                .await?
+                .iter()
+                .map(|&reference| async move {
+                    Ok(Vc::try_resolve_downcast::<Box<dyn OutputAsset>>(reference).await?)
+                })
+                .try_join()
+                .await?
+                .into_iter()
+                .flatten(),
+        );
+    }
+    Ok(files)
+}
+
+/// Returns the paths of the files whose content contains `needle`.
+fn containing<'a>(files: &'a BTreeMap<String, String>, needle: &str) -> Vec<&'a str> {
+    files
+        .iter()
+        .filter(|(_, content)| content.contains(needle))
+        .map(|(path, _)| path.as_str())
+        .collect()
+}
+
+#[tokio::test]
+async fn builds_are_deterministic() {
+    register();
+    let mut builds = Vec::new();
+    // Each build runs on a fresh backend so that nothing is shared between
+    // them.
+    for _ in 0..2 {
+        let tt = TurboTasks::new(MemoryBackend::default());
+        builds.push(
+            tt.run_once(async { build("deterministic", MinifyType::NoMinify).await })
+                .await
+                .unwrap(),
+        );
+    }
+    let [first, second] = &builds[..] else {
+        unreachable!()
+    };
+    // The dynamic import adds a second chunk group whose items are shared
+    // with the entry.
+    assert!(
+        !containing(first, r#"whisper("lazy")"#).is_empty(),
+        "{first:#?}"
+    );
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn worker_gets_its_own_chunk_group() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let files = build("worker", MinifyType::NoMinify).await?;
+
+        let entry = containing(&files, r#"worker.postMessage("hello")"#);
+        assert_eq!(entry.len(), 1, "{files:#?}");
+        let worker = containing(&files, "from worker");
+        assert_eq!(worker.len(), 1, "{files:#?}");
+        assert_ne!(
+            entry, worker,
+            "the worker must not be bundled into the entry"
+        );
+
+        // The `new URL(...)` argument is replaced with the worker loader, which
+        // turns the worker's chunks into a blob URL.
+        assert!(containing(&files, "./worker.js").is_empty(), "{files:#?}");
+        assert_eq!(
+            containing(&files, "__turbopack_worker_blob_url__(["),
+            entry,
+            "{files:#?}"
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn url_constructor_references_static_asset() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let files = build("url-image", MinifyType::NoMinify).await?;
+
+        // The request is replaced with the URL of the emitted image, resolved
+        // relative to the chunk.
+        assert!(containing(&files, "./logo.png").is_empty(), "{files:#?}");
+        let entry = containing(&files, "img.src = logoUrl.href");
+        assert_eq!(entry.len(), 1, "{files:#?}");
+        assert_eq!(
+            containing(
+                &files,
+                "new __turbopack_relative_url__(__turbopack_require__("
+            ),
+            entry,
+            "{files:#?}"
+        );
+        assert_eq!(
+            containing(&files, r#"__turbopack_export_value__("/static/logo."#).len(),
+            1,
+            "{files:#?}"
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn minification_keeps_exports_and_source_maps() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let original = build("minify", MinifyType::NoMinify).await?;
+        let minified = build("minify", MinifyType::Minify).await?;
+
+        let size =
+            |files: &BTreeMap<String, String>| files.values().map(String::len).sum::<usize>();
+        assert!(size(&minified) < size(&original), "{minified:#?}");
+        assert!(!containing(&original, "let result = 0").is_empty());
+        assert!(containing(&minified, "let result = 0").is_empty());
+
+        // Export names are part of the module's interface and must survive
+        // mangling, and the minified code still points to its source map.
+        let exporting = containing(&minified, "DEFAULT_FACTOR");
+        assert!(!exporting.is_empty(), "{minified:#?}");
+        for path in exporting {
+            let code = &minified[path];
+            assert!(code.contains("multiply"), "{code}");
+            assert!(code.contains("//# sourceMappingURL="), "{code}");
+        }
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn entry_chunks_manifest_lists_each_entry() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let root = fixture_root("two-entries");
+        let environment = browser_environment();
+        let asset_context = asset_context(environment);
+        let output_root = root.join("output".into());
+        let chunking_context = chunking_context(
+            root,
+            output_root,
+            output_root.join("chunks".into()),
+            environment,
+            MinifyType::NoMinify,
+        );
+        let main =
+            entry_chunks(asset_context, chunking_context, root.join("main.js".into())).await?;
+        let admin = entry_chunks(
+            asset_context,
+            chunking_context,
+            root.join("admin.js".into()),
+        )
+        .await?;
+
+        let manifest = chunking_context.entry_chunks_manifest(
+            output_root.join("manifest.json".into()),
+            vec![("main".into(), main), ("admin".into(), admin)],
+        );
+        let FileContent::Content(file) = &*manifest.content().file_content().await? else {
+            bail!("the manifest has no content");
+        };
+        let json = file.content().to_str()?.into_owned();
+        let manifest: BTreeMap<String, Vec<String>> = serde_json::from_str(&json)?;
+
+        let output_root_path = output_root.await?;
+        let mut expected = BTreeMap::new();
+        for (name, chunks) in [("main", main), ("admin", admin)] {
+            let mut paths = Vec::new();
+            for chunk in chunks.await?.iter() {
+                let path = chunk.ident().path().await?;
+                let Some(relative) = output_root_path.get_path_to(&path) else {
+                    bail!("{} is outside of the output root", path.path);
+                };
+                paths.push(relative.to_string());
+            }
+            assert!(!paths.is_empty(), "{name} has no chunks");
+            expected.insert(name.to_string(), paths);
+        }
+        assert_ne!(expected["main"], expected["admin"]);
+        // each entry lists exactly its own chunk group, in chunk group order
+        assert_eq!(manifest, expected, "{json}");
+        // entries are emitted sorted by name
+        assert!(json.find(r#""admin""#) < json.find(r#""main""#), "{json}");
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn entry_chunks_manifest_rejects_chunks_outside_of_the_output_root() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let root = fixture_root("two-entries");
+        let environment = browser_environment();
+        let output_root = root.join("output".into());
+        let chunking_context = chunking_context(
+            root,
+            output_root,
+            root.join("elsewhere".into()),
+            environment,
+            MinifyType::NoMinify,
+        );
+        let main = entry_chunks(
+            asset_context(environment),
+            chunking_context,
+            root.join("main.js".into()),
+        )
+        .await?;
+
+        let manifest = chunking_context.entry_chunks_manifest(
+            output_root.join("manifest.json".into()),
+            vec![("main".into(), main)],
+        );
+        let error = manifest
+            .content()
+            .file_content()
+            .await
+            .err()
+            .expect("chunks outside of the output root must not be dropped silently");
+        assert!(
+            format!("{error:?}").contains("outside of the output root"),
+            "{error:?}"
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn base_paths_without_trailing_slash() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let root = fixture_root("url-image");
+        let output_root = root.join("output".into());
+        let entry = root.join("index.js".into());
+        let base_path = Vc::cell(Some("/assets".into()));
+
+        let environment = browser_environment();
+        let chunking_context = Vc::upcast::<Box<dyn ChunkingContext>>(
+            BrowserChunkingContext::builder(
+                root,
+                output_root,
+                output_root,
+                output_root.join("chunks".into()),
+                output_root.join("static".into()),
+                environment,
+                Default::default(),
+            )
+            .chunk_base_path(base_path)
+            .asset_base_path(base_path)
+            .build(),
+        );
+        let chunks = entry_chunks(asset_context(environment), chunking_context, entry).await?;
+        let files = js_files(output_root, chunks).await?;
+        assert_eq!(
+            containing(&files, r#"const CHUNK_BASE_PATH = "/assets/";"#).len(),
+            1,
+            "{files:#?}"
+        );
+        assert_eq!(
+            containing(
+                &files,
+                r#"__turbopack_export_value__("/assets/static/logo."#
+            )
+            .len(),
+            1,
+            "{files:#?}"
+        );
+
+        let environment = Environment::new(Value::new(ExecutionEnvironment::NodeJsBuildTime(
+            NodeJsEnvironment::default().into(),
+        )));
+        let chunking_context = Vc::upcast::<Box<dyn ChunkingContext>>(
+            NodeJsChunkingContext::builder(
+                root,
+                output_root,
+                output_root,
+                output_root.join("chunks".into()),
+                output_root.join("static".into()),
+                environment,
+                Default::default(),
+            )
+            .asset_prefix(base_path)
+            .build(),
+        );
+        let module = asset_context(environment)
+            .process(
+                Vc::upcast(FileSource::new(entry)),
+                Value::new(ReferenceType::Entry(EntryReferenceSubType::Undefined)),
+            )
+            .module();
+        let entry_chunk = chunking_context.entry_chunk_group_asset(
+            output_root.join("index.js".into()),
+            module,
+            EvaluatableAssets::empty(),
+            Value::new(AvailabilityInfo::Root),
+        );
+        let files = js_files(output_root, Vc::cell(vec![entry_chunk])).await?;
+        assert_eq!(
+            containing(&files, r#"const ASSET_PREFIX = "/assets/";"#).len(),
+            1,
+            "{files:#?}"
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
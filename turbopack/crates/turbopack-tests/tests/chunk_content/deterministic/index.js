@@ -0,0 +1,5 @@
+import { shout } from "./shout";
+import { whisper } from "./whisper";
+
+console.log(shout("hello"), whisper("hello"));
+import("./lazy").then(({ default: lazy }) => lazy());
@@ -0,0 +1,5 @@
+import { whisper } from "./whisper";
+
+export default function lazy() {
+  console.log(whisper("lazy"));
+}
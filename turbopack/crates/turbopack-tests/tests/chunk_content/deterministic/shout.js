@@ -0,0 +1,3 @@
+import { format } from "./format";
+
+export const shout = (text) => format(text).toUpperCase();
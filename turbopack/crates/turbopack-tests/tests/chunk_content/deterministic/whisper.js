@@ -0,0 +1,3 @@
+import { format } from "./format";
+
+export const whisper = (text) => format(text).toLowerCase();
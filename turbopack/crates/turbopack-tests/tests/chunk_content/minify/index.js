@@ -0,0 +1,3 @@
+import { DEFAULT_FACTOR, multiply } from "./math";
+
+console.log(multiply(7, DEFAULT_FACTOR));
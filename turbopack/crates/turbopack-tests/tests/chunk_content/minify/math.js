@@ -0,0 +1,13 @@
+export function add(first, second) {
+  return first + second;
+}
+
+export function multiply(first, second) {
+  let result = 0;
+  for (let i = 0; i < second; i++) {
+    result = add(result, first);
+  }
+  return result;
+}
+
+export const DEFAULT_FACTOR = 3;
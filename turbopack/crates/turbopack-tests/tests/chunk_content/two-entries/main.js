@@ -0,0 +1,3 @@
+import { greet } from "./shared";
+
+console.log(greet("main"));
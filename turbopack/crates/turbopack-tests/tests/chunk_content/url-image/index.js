@@ -0,0 +1,5 @@
+const logoUrl = new URL("./logo.png", import.meta.url);
+
+const img = document.createElement("img");
+img.src = logoUrl.href;
+document.body.appendChild(img);
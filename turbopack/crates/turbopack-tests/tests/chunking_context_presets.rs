@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use anyhow::Result;
+use turbo_tasks::{TurboTasks, Value, Vc};
+use turbo_tasks_fs::{File, FileSystem, FileSystemPath, VirtualFileSystem};
+use turbo_tasks_memory::MemoryBackend;
+use turbopack_browser::BrowserChunkingContext;
+use turbopack_core::{
+    asset::AssetContent,
+    chunk::{ChunkingContext, MinifyType, ModuleId},
+    environment::{BrowserEnvironment, Environment, ExecutionEnvironment, NodeJsEnvironment},
+    ident::AssetIdent,
+    virtual_output::VirtualOutputAsset,
+};
+use turbopack_nodejs::NodeJsChunkingContext;
+
+fn register() {
+    turbo_tasks::register();
+    turbo_tasks_fs::register();
+    turbopack::register();
+    turbopack_browser::register();
+    turbopack_nodejs::register();
+    turbopack_ecmascript_runtime::register();
+}
+
+fn chunking_context(root: Vc<FileSystemPath>, production: bool) -> Vc<BrowserChunkingContext> {
+    let environment = Environment::new(Value::new(ExecutionEnvironment::Browser(
+        BrowserEnvironment {
+            dom: true,
+            web_worker: false,
+            service_worker: false,
+            browserslist_query: "last 1 Chrome versions".into(),
+        }
+        .into(),
+    )));
+    let preset = if production {
+        BrowserChunkingContext::production
+    } else {
+        BrowserChunkingContext::development
+    };
+    preset(
+        root,
+        root.join("output".into()),
+        root.join("output".into()),
+        root.join("output/chunks".into()),
+        root.join("output/assets".into()),
+        environment,
+    )
+    .build()
+}
+
+fn node_chunking_context(root: Vc<FileSystemPath>, production: bool) -> Vc<NodeJsChunkingContext> {
+    let environment = Environment::new(Value::new(ExecutionEnvironment::NodeJsBuildTime(
+        NodeJsEnvironment::default().into(),
+    )));
+    let preset = if production {
+        NodeJsChunkingContext::production
+    } else {
+        NodeJsChunkingContext::development
+    };
+    preset(
+        root,
+        root.join("output".into()),
+        root.join("output".into()),
+        root.join("output/chunks".into()),
+        root.join("output/assets".into()),
+        environment,
+    )
+    .build()
+}
+
+async fn module_id(
+    chunking_context: Vc<Box<dyn ChunkingContext>>,
+    path: Vc<FileSystemPath>,
+) -> Result<ModuleId> {
+    Ok(chunking_context
+        .chunk_item_id_from_ident(AssetIdent::from_path(path))
+        .await?
+        .clone_value())
+}
+
+/// Whether a JavaScript chunk emitted by `chunking_context` references its
+/// source map.
+async fn references_source_maps(
+    chunking_context: Vc<Box<dyn ChunkingContext>>,
+    root: Vc<FileSystemPath>,
+) -> Result<bool> {
+    let chunk = VirtualOutputAsset::new(
+        root.join("output/chunks/index.js".into()),
+        AssetContent::file(File::from("").into()),
+    );
+    Ok(*chunking_context
+        .reference_chunk_source_maps(Vc::upcast(chunk))
+        .await?)
+}
+
+#[tokio::test]
+async fn development_and_production_presets() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let root = VirtualFileSystem::new().root();
+        let path = root.join("src/index.js".into());
+
+        let development = chunking_context(root, false);
+        assert!(
+            *Vc::upcast::<Box<dyn ChunkingContext>>(development)
+                .is_hot_module_replacement_enabled()
+                .await?
+        );
+        assert_eq!(development.await?.minify_type(), MinifyType::NoMinify);
+        assert!(references_source_maps(Vc::upcast(development), root).await?);
+        let ModuleId::String(id) = module_id(Vc::upcast(development), path).await? else {
+            panic!("expected a readable module id");
+        };
+        assert!(id.contains("src/index.js"), "{id}");
+
+        let production = chunking_context(root, true);
+        assert!(
+            !*Vc::upcast::<Box<dyn ChunkingContext>>(production)
+                .is_hot_module_replacement_enabled()
+                .await?
+        );
+        assert_eq!(production.await?.minify_type(), MinifyType::Minify);
+        assert!(!references_source_maps(Vc::upcast(production), root).await?);
+        let ModuleId::String(id) = module_id(Vc::upcast(production), path).await? else {
+            panic!("expected a hashed module id");
+        };
+        assert!(!id.contains("index.js"), "{id}");
+        assert!(id.chars().all(|c| c.is_ascii_digit()), "{id}");
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn node_development_and_production_presets() {
+    register();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    tt.run_once(async {
+        let root = VirtualFileSystem::new().root();
+        let path = root.join("src/index.js".into());
+
+        let development = node_chunking_context(root, false);
+        // Node.js chunks are never hot reloaded.
+        assert!(
+            !*Vc::upcast::<Box<dyn ChunkingContext>>(development)
+                .is_hot_module_replacement_enabled()
+                .await?
+        );
+        assert_eq!(development.await?.minify_type(), MinifyType::NoMinify);
+        assert!(references_source_maps(Vc::upcast(development), root).await?);
+        let ModuleId::String(id) = module_id(Vc::upcast(development), path).await? else {
+            panic!("expected a readable module id");
+        };
+        assert!(id.contains("src/index.js"), "{id}");
+
+        let production = node_chunking_context(root, true);
+        assert_eq!(production.await?.minify_type(), MinifyType::Minify);
+        assert!(!references_source_maps(Vc::upcast(production), root).await?);
+        let ModuleId::String(id) = module_id(Vc::upcast(production), path).await? else {
+            panic!("expected a hashed module id");
+        };
+        assert!(!id.contains("index.js"), "{id}");
+        assert!(id.chars().all(|c| c.is_ascii_digit()), "{id}");
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -182,6 +182,8 @@ async fn run(resource: PathBuf, snapshot_mode: IssueSnapshotMode) -> Result<JsRe
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct TestOptions {
     tree_shaking_mode: Option<TreeShakingMode>,
+    #[serde(default)]
+    preserve_directives: bool,
 }
 
 #[turbo_tasks::value]
@@ -283,6 +285,7 @@ async fn run_test(prepared_test: Vc<PreparedTest>) -> Result<Vc<RunTestResult>>
             ecmascript: EcmascriptOptionsContext {
                 enable_typescript_transform: Some(Default::default()),
                 import_externals: true,
+                preserve_directives: options.preserve_directives,
                 ..Default::default()
             },
             preset_env_versions: Some(env),
@@ -0,0 +1,29 @@
+import pkg, { name, version, nested } from "./pkg.json";
+import * as ns from "./pkg.json";
+import array from "./array.json";
+import number from "./number.json";
+
+it("should expose top-level keys of a JSON object as named exports", () => {
+  expect(name).toBe("json-named-exports");
+  expect(version).toBe("1.2.3");
+  expect(nested).toEqual({ ok: true });
+});
+
+it("should expose the whole JSON object as the default export", () => {
+  expect(pkg).toEqual({
+    name: "json-named-exports",
+    version: "1.2.3",
+    nested: { ok: true },
+  });
+  expect(ns.default).toBe(pkg);
+  expect(ns.name).toBe(name);
+});
+
+it("should expose non-object JSON values as the default export", () => {
+  expect(array).toEqual([1, 2, 3]);
+  expect(number).toBe(42);
+});
+
+it("should return the plain JSON value from require", () => {
+  expect(require("./pkg.json")).toBe(pkg);
+});
@@ -0,0 +1,15 @@
+it("should resolve a dynamically imported JSON module to its value", async () => {
+  const data = await import("./data.json");
+  expect(data.default).toEqual({
+    name: "dynamic-import-json",
+    nested: { values: [1, 2, 3] },
+  });
+});
+
+it("should resolve repeated dynamic imports of a JSON module to the same value", async () => {
+  const [a, b] = await Promise.all([
+    import("./data.json"),
+    import("./data.json"),
+  ]);
+  expect(a.default).toBe(b.default);
+});
@@ -0,0 +1,11 @@
+import strict from "./strict.js";
+import sloppy from "./sloppy.js";
+
+it("should keep \"use strict\" local to the module that declares it", () => {
+  expect(strict.isStrict).toBe(true);
+  expect(strict.dep).toBe("dep");
+});
+
+it("should not make sloppy modules in the same chunk strict", () => {
+  expect(sloppy.isStrict).toBe(false);
+});
@@ -0,0 +1,5 @@
+module.exports = {
+  isStrict: (function () {
+    return this === undefined;
+  })(),
+};
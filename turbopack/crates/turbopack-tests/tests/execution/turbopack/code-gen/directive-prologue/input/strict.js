@@ -0,0 +1,10 @@
+"use strict";
+
+const dep = require("./dep.js");
+
+module.exports = {
+  isStrict: (function () {
+    return this === undefined;
+  })(),
+  dep,
+};
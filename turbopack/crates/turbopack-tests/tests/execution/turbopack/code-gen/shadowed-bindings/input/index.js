@@ -0,0 +1,31 @@
+import { value } from "./value.js";
+
+function callRequire(require) {
+  return require("./value.js");
+}
+
+function readShadowed() {
+  const value = "local";
+  return value;
+}
+
+function readInBlock() {
+  let result;
+  {
+    const value = "block";
+    result = value;
+  }
+  return [result, value];
+}
+
+it("should not treat a parameter named require as the module require", () => {
+  expect(callRequire((request) => `called with ${request}`)).toBe(
+    "called with ./value.js"
+  );
+});
+
+it("should keep local bindings that shadow an import", () => {
+  expect(readShadowed()).toBe("local");
+  expect(readInBlock()).toEqual(["block", "imported"]);
+  expect(value).toBe("imported");
+});
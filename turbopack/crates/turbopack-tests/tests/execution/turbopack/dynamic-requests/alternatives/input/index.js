@@ -0,0 +1,12 @@
+const requireEither = (useA) => require(useA ? "./dir/a.js" : "./dir/b.js");
+const importEither = (useA) => import(useA ? "./dir/a.js" : "./dir/b.js");
+
+it("should support require with a constrained set of alternatives", () => {
+  expect(requireEither(true)).toBe("a");
+  expect(requireEither(false)).toBe("b");
+});
+
+it("should support import with a constrained set of alternatives", async () => {
+  await expect(importEither(true)).resolves.toHaveProperty("default", "a");
+  await expect(importEither(false)).resolves.toHaveProperty("default", "b");
+});
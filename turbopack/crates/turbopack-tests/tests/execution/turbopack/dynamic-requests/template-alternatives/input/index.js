@@ -0,0 +1,16 @@
+const loadPage = (home) => {
+  const name = home ? "home" : "about";
+  return import(`./pages/${name}`);
+};
+
+it("should support import of a template with a constrained set of alternatives", async () => {
+  await expect(loadPage(true)).resolves.toHaveProperty("default", "home");
+  await expect(loadPage(false)).resolves.toHaveProperty("default", "about");
+});
+
+it("should load each alternative lazily", async () => {
+  const home = await loadPage(true);
+  const about = await loadPage(false);
+  expect(home).not.toBe(about);
+  expect(await loadPage(true)).toBe(home);
+});
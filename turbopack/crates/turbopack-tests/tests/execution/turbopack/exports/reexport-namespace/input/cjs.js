@@ -0,0 +1 @@
+exports.foo = "cjs-foo";
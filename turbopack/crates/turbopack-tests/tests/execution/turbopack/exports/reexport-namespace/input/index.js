@@ -0,0 +1,25 @@
+import * as mod from "./reexport";
+import { utils, cjs } from "./reexport";
+
+it("should expose a re-exported namespace as an object", () => {
+  expect(mod.utils.foo).toBe("foo");
+  expect(mod.utils.bar()).toBe("bar");
+  expect(utils.foo).toBe("foo");
+  expect(Object.keys(mod.utils).sort()).toEqual(["bar", "foo"]);
+});
+
+it("should keep the re-exported namespace object identity", async () => {
+  const direct = await import("./utils");
+  expect(mod.utils).toBe(direct);
+});
+
+it("should expose live bindings through a re-exported namespace", () => {
+  expect(mod.live.counter).toBe(0);
+  mod.live.increment();
+  expect(mod.live.counter).toBe(1);
+});
+
+it("should expose a re-exported CommonJS namespace", () => {
+  expect(cjs.foo).toBe("cjs-foo");
+  expect(cjs.default).toEqual({ foo: "cjs-foo" });
+});
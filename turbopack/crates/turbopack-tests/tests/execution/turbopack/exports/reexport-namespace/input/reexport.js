@@ -0,0 +1,3 @@
+export * as utils from "./utils";
+export * as live from "./live";
+export * as cjs from "./cjs";
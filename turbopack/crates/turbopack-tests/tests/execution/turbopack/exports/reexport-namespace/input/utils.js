@@ -0,0 +1,5 @@
+export const foo = "foo";
+
+export function bar() {
+  return "bar";
+}
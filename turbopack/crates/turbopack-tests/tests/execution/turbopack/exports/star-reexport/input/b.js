@@ -0,0 +1,3 @@
+export * from "./shared";
+export { shared as renamed } from "./shared";
+export const onlyB = "b";
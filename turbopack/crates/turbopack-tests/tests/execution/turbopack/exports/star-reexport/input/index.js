@@ -0,0 +1,13 @@
+import * as ns from "./reexport";
+import { shared, renamed, onlyA, onlyB } from "./reexport";
+
+it("should merge non-conflicting star re-exports", () => {
+  expect(onlyA).toBe("a");
+  expect(onlyB).toBe("b");
+  expect(Object.keys(ns).sort()).toEqual(["onlyA", "onlyB", "renamed", "shared"]);
+});
+
+it("should not treat the same binding reached through multiple star re-exports as ambiguous", () => {
+  expect(shared).toBe("shared");
+  expect(renamed).toBe("shared");
+});
@@ -0,0 +1,15 @@
+import foo from "pkg/features/foo";
+import special from "pkg/features/special";
+import nestedBar from "pkg/features/nested/bar";
+
+it("should expand wildcards on both sides of an exports subpath pattern", () => {
+  expect(foo).toBe("foo");
+});
+
+it("should prefer an exact exports key over a matching subpath pattern", () => {
+  expect(special).toBe("special");
+});
+
+it("should prefer the longest matching subpath pattern", () => {
+  expect(nestedBar).toBe("nested bar");
+});
@@ -48,7 +48,7 @@ impl AggregatedGraph {
         Ok(match *self.await? {
             AggregatedGraph::Leaf(asset) => {
                 let mut refs = HashSet::new();
-                for reference in asset.references().await?.iter() {
+                for reference in referenced_assets(asset).await? {
                     let reference = reference.resolve().await?;
                     if asset != reference {
                         refs.insert(AggregatedGraph::leaf(reference));
@@ -74,7 +74,9 @@ impl AggregatedGraph {
     #[turbo_tasks::function]
     async fn cost(self: Vc<Self>) -> Result<Vc<AggregationCost>> {
         Ok(match *self.await? {
-            AggregatedGraph::Leaf(asset) => AggregationCost(asset.references().await?.len()).into(),
+            AggregatedGraph::Leaf(asset) => {
+                AggregationCost(referenced_assets(asset).await?.len()).into()
+            }
             AggregatedGraph::Node { ref references, .. } => {
                 AggregationCost(references.len()).into()
             }
@@ -113,6 +115,20 @@ impl AggregatedGraph {
     }
 }
 
+/// The assets emitted because of `asset`: its references and its auxiliary
+/// assets.
+async fn referenced_assets(
+    asset: Vc<Box<dyn OutputAsset>>,
+) -> Result<Vec<Vc<Box<dyn OutputAsset>>>> {
+    Ok(asset
+        .references()
+        .await?
+        .iter()
+        .chain(asset.auxiliary_assets().await?.iter())
+        .copied()
+        .collect())
+}
+
 #[turbo_tasks::function]
 pub async fn aggregate(asset: Vc<Box<dyn OutputAsset>>) -> Result<Vc<AggregatedGraph>> {
     let mut current = AggregatedGraph::leaf(asset);
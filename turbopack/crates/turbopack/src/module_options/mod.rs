@@ -17,7 +17,10 @@ use turbopack_core::{
     resolve::options::{ImportMap, ImportMapping},
 };
 use turbopack_css::CssModuleAssetType;
-use turbopack_ecmascript::{EcmascriptInputTransform, EcmascriptOptions, SpecifiedModuleType};
+use turbopack_ecmascript::{
+    EcmascriptInputTransform, EcmascriptInputTransformsBuilder, EcmascriptOptions,
+    SpecifiedModuleType,
+};
 use turbopack_mdx::MdxTransform;
 use turbopack_node::transforms::{postcss::PostCssTransform, webpack::WebpackLoaders};
 use turbopack_wasm::source::WebAssemblySourceType;
@@ -76,6 +79,8 @@ impl ModuleOptions {
                     import_externals,
                     esm_url_rewrite_behavior,
                     ref enable_typeof_window_inlining,
+                    special_cases,
+                    preserve_directives,
                     ..
                 },
             enable_mdx,
@@ -135,6 +140,8 @@ impl ModuleOptions {
             import_externals,
             ignore_dynamic_requests,
             refresh,
+            special_cases,
+            preserve_directives,
             ..Default::default()
         };
         let ecmascript_options_vc = ecmascript_options.cell();
@@ -178,18 +185,11 @@ impl ModuleOptions {
 
         let vendor_transforms = Vc::cell(vec![]);
         let ts_app_transforms = if let Some(transform) = &ts_transform {
-            let base_transforms = if let Some(decorators_transform) = &decorators_transform {
-                vec![decorators_transform.clone(), transform.clone()]
-            } else {
-                vec![transform.clone()]
-            };
-            Vc::cell(
-                base_transforms
-                    .iter()
-                    .cloned()
-                    .chain(transforms.iter().cloned())
-                    .collect(),
-            )
+            EcmascriptInputTransformsBuilder::new()
+                .extend(decorators_transform.clone())
+                .push(transform.clone())
+                .extend(transforms.iter().cloned())
+                .build()?
         } else {
             Vc::cell(transforms.clone())
         };
@@ -202,17 +202,10 @@ impl ModuleOptions {
         // Since typescript transform (`ts_app_transforms`) needs to apply decorators
         // _before_ stripping types, we create ts_app_transforms first in a
         // specific order with typescript, then apply decorators to app_transforms.
-        let app_transforms = Vc::cell(
-            if let Some(decorators_transform) = &decorators_transform {
-                vec![decorators_transform.clone()]
-            } else {
-                vec![]
-            }
-            .iter()
-            .cloned()
-            .chain(transforms.iter().cloned())
-            .collect(),
-        );
+        let app_transforms = EcmascriptInputTransformsBuilder::new()
+            .extend(decorators_transform.clone())
+            .extend(transforms.iter().cloned())
+            .build()?;
 
         let mut rules = vec![
             ModuleRule::new_all(
@@ -4,7 +4,7 @@ use turbo_tasks::{trace::TraceRawVcs, RcStr, ValueDefault, Vc};
 use turbopack_core::{
     condition::ContextCondition, environment::Environment, resolve::options::ImportMapping,
 };
-use turbopack_ecmascript::{references::esm::UrlRewriteBehavior, TreeShakingMode};
+use turbopack_ecmascript::{references::esm::UrlRewriteBehavior, SpecialCases, TreeShakingMode};
 pub use turbopack_mdx::MdxTransformOptions;
 use turbopack_node::{
     execution_context::ExecutionContext,
@@ -156,6 +156,12 @@ pub struct EcmascriptOptionsContext {
     /// If false, they will reference the whole directory. If true, they won't
     /// reference anything and lead to an runtime error instead.
     pub ignore_dynamic_requests: bool,
+    /// Additional references to add to modules matching a path pattern, e.g.
+    /// to include data files a package loads dynamically.
+    pub special_cases: Option<Vc<SpecialCases>>,
+    /// Emit each module's directive prologue (e.g. `"use strict"`) from its
+    /// module factory, so it stays local to the module.
+    pub preserve_directives: bool,
 
     pub placeholder_for_future_extensions: (),
 }
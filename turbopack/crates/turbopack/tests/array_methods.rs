@@ -0,0 +1,47 @@
+#![cfg(test)]
+
+mod helpers;
+
+use turbo_tasks::RcStr;
+use turbo_tasks_testing::{register, run, Registration};
+
+use crate::helpers::{asset_context, fixture_root, process, referenced_paths};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[tokio::test]
+async fn requires_in_array_callbacks() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("array_methods");
+        let asset_context = asset_context(Default::default(), Default::default());
+        let paths = referenced_paths(process(asset_context, root.join("index.js".into()))).await?;
+        for expected in ["a.js", "b.js", "c.js", "d.js", "e.js", "f.js"] {
+            assert!(
+                paths.iter().any(|path| path == expected),
+                "expected a reference to {expected}, got {paths:?}"
+            );
+        }
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn filtered_arrays_are_not_folded() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("array_methods");
+        let asset_context = asset_context(Default::default(), Default::default());
+        let paths = referenced_paths(process(
+            asset_context,
+            root.join("filtered_length.js".into()),
+        ))
+        .await?;
+        assert_eq!(paths, vec![RcStr::from("empty.js")]);
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
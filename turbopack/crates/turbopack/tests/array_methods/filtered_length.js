@@ -0,0 +1,4 @@
+// The filtered array is empty at runtime, so this branch must be kept.
+if ([1, 2].filter((n) => n > 2).length === 0) {
+  require("./empty.js");
+}
@@ -0,0 +1,7 @@
+["./a.js", "./b.js"].forEach((m) => require(m));
+
+["./c.js"].concat(["./d.js"]).map((m) => require(m));
+
+["./e.js", "./f.js"]
+  .filter((m) => m.endsWith(".js"))
+  .forEach((m) => require(m));
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+mod helpers;
+
+use turbo_tasks::{Value, Vc};
+use turbo_tasks_fs::{File, FileSystemPath};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    asset::AssetContent, context::AssetContext, module::Module, reference_type::ReferenceType,
+    virtual_source::VirtualSource,
+};
+use turbopack_ecmascript::EcmascriptModuleAsset;
+use turbopack_json::JsonModuleAsset;
+use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+use turbopack_static::StaticModuleAsset;
+
+use crate::helpers::{fixture_root, process, referenced_paths};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+fn asset_context(root: Vc<FileSystemPath>) -> Vc<Box<dyn AssetContext>> {
+    helpers::asset_context(
+        Default::default(),
+        ResolveOptionsContext {
+            enable_node_modules: Some(root),
+            ..Default::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn virtual_source_resolves_relative_imports() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("asset_context");
+        // The virtual entry has no backing file, but lives next to real files.
+        let source = VirtualSource::new(
+            root.join("virtual-entry.js".into()),
+            AssetContent::file(
+                File::from("import { dep } from \"./dep.js\";\nconsole.log(dep);\n").into(),
+            ),
+        );
+        let module = asset_context(root)
+            .process(Vc::upcast(source), Value::new(ReferenceType::Undefined))
+            .module();
+
+        assert_eq!(&*module.ident().path().await?.path, "virtual-entry.js");
+        let paths = referenced_paths(module).await?;
+        assert!(
+            paths.iter().any(|path| path == "dep.js"),
+            "expected the virtual entry to reference dep.js, got {paths:?}"
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn process_creates_module_for_source_type() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("asset_context");
+        let context = asset_context(root);
+        let module = |path: &str| process(context, root.join(path.into()));
+
+        assert!(
+            Vc::try_resolve_downcast_type::<EcmascriptModuleAsset>(module("dep.js"))
+                .await?
+                .is_some()
+        );
+        assert!(
+            Vc::try_resolve_downcast_type::<JsonModuleAsset>(module("data.json"))
+                .await?
+                .is_some()
+        );
+        assert!(
+            Vc::try_resolve_downcast_type::<StaticModuleAsset>(module("image.png"))
+                .await?
+                .is_some()
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
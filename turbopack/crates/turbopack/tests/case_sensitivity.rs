@@ -0,0 +1,28 @@
+#![cfg(test)]
+
+mod helpers;
+
+use turbo_tasks::RcStr;
+use turbo_tasks_testing::{register, run, Registration};
+
+use crate::helpers::{asset_context, fixture_root, process, referenced_paths};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[tokio::test]
+async fn imports_match_file_names_exactly() {
+    run(&REGISTRATION, || async {
+        // `./Foo` must not resolve to `foo.js`, even on a case-insensitive
+        // filesystem, since paths are looked up by their on-disk names.
+        let root = fixture_root("case_sensitivity");
+        let module = process(
+            asset_context(Default::default(), Default::default()),
+            root.join("index.js".into()),
+        );
+        assert_eq!(referenced_paths(module).await?, vec![RcStr::from("bar.js")]);
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,2 @@
+import "./Foo";
+import "./bar";
@@ -1,6 +1,88 @@
+// Each test binary only uses some of these helpers.
+#![allow(dead_code)]
+
 use std::{collections::VecDeque, fmt::Write as _};
 
+use anyhow::Result;
 use difference::{Changeset, Difference};
+use turbo_tasks::{RcStr, Value, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath};
+use turbopack::{module_options::ModuleOptionsContext, ModuleAssetContext};
+use turbopack_core::{
+    compile_time_info::CompileTimeInfo,
+    context::AssetContext,
+    environment::{Environment, ExecutionEnvironment, NodeJsEnvironment},
+    file_source::FileSource,
+    module::{Module, Modules},
+    reference::primary_referenced_modules,
+    reference_type::ReferenceType,
+};
+use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+
+/// Returns the root of the fixture directory `tests/<name>`.
+pub fn fixture_root(name: &str) -> Vc<FileSystemPath> {
+    let fs = DiskFileSystem::new(
+        "fixture".into(),
+        format!("{}/tests/{name}", env!("CARGO_MANIFEST_DIR")).into(),
+        vec![],
+    );
+    Vc::upcast::<Box<dyn FileSystem>>(fs).root()
+}
+
+/// Creates an asset context for a Node.js target.
+pub fn asset_context(
+    module_options: ModuleOptionsContext,
+    resolve_options: ResolveOptionsContext,
+) -> Vc<Box<dyn AssetContext>> {
+    asset_context_for(
+        ExecutionEnvironment::NodeJsLambda(NodeJsEnvironment::default().into()),
+        module_options,
+        resolve_options,
+    )
+}
+
+/// Creates an asset context for the given target.
+pub fn asset_context_for(
+    execution: ExecutionEnvironment,
+    module_options: ModuleOptionsContext,
+    resolve_options: ResolveOptionsContext,
+) -> Vc<Box<dyn AssetContext>> {
+    Vc::upcast(ModuleAssetContext::new(
+        Default::default(),
+        CompileTimeInfo::new(Environment::new(Value::new(execution))),
+        module_options.cell(),
+        resolve_options.cell(),
+        Vc::cell("test".into()),
+    ))
+}
+
+/// Processes the file at `path` into a module.
+pub fn process(
+    asset_context: Vc<Box<dyn AssetContext>>,
+    path: Vc<FileSystemPath>,
+) -> Vc<Box<dyn Module>> {
+    asset_context
+        .process(
+            Vc::upcast(FileSource::new(path)),
+            Value::new(ReferenceType::Undefined),
+        )
+        .module()
+}
+
+/// Returns the sorted paths of `modules`.
+pub async fn paths(modules: Vc<Modules>) -> Result<Vec<RcStr>> {
+    let mut paths = Vec::new();
+    for &module in modules.await?.iter() {
+        paths.push(module.ident().path().await?.path.clone());
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Returns the sorted paths of the modules `module` references.
+pub async fn referenced_paths(module: Vc<Box<dyn Module>>) -> Result<Vec<RcStr>> {
+    paths(primary_referenced_modules(module)).await
+}
 
 pub fn print_changeset(changeset: &Changeset) -> String {
     assert!(changeset.split == "\n");
@@ -0,0 +1,101 @@
+#![cfg(test)]
+#![feature(arbitrary_self_types)]
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_fs::{FileSystem, FileSystemPath, VirtualFileSystem};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::issue::{
+    Issue, IssueDescriptionExt, IssueExt, IssueStage, StyledString, MAX_ISSUE_PROCESSING_PATH_DEPTH,
+};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[turbo_tasks::value]
+struct TestIssue {
+    path: Vc<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for TestIssue {
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Misc.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("deep issue".into()).cell()
+    }
+}
+
+/// Emits an issue `depth` levels deep, attaching one processing path item per
+/// level.
+#[turbo_tasks::function]
+async fn nested(root: Vc<FileSystemPath>, depth: u32) -> Result<Vc<()>> {
+    let path = root.join(format!("{depth}.js").into());
+    if depth == 0 {
+        TestIssue { path }.cell().emit();
+    } else {
+        nested(root, depth - 1)
+            .issue_file_path(path, "expanding references of asset")
+            .await?
+            .await?;
+    }
+    Ok(Vc::cell(()))
+}
+
+async fn processing_path(depth: u32) -> Result<Vec<RcStr>> {
+    let root = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new()).root();
+    let result = nested(root, depth);
+    result.strongly_consistent().await?;
+    let issues = result
+        .peek_issues_with_path()
+        .await?
+        .get_plain_issues()
+        .await?;
+    assert_eq!(issues.len(), 1);
+    let path = issues[0]
+        .processing_path
+        .as_ref()
+        .expect("the issue should have a processing path");
+    Ok(path
+        .iter()
+        .map(|item| (**item.file_path.as_ref().unwrap()).clone())
+        .collect())
+}
+
+#[tokio::test]
+async fn issue_carries_processing_path() {
+    run(&REGISTRATION, || async {
+        let path = processing_path(3).await?;
+        assert_eq!(path.len(), 3);
+        assert!(path[0].ends_with("/3.js"));
+        assert!(path[1].ends_with("/2.js"));
+        assert!(path[2].ends_with("/1.js"));
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn issue_processing_path_depth_is_capped() {
+    run(&REGISTRATION, || async {
+        let depth = MAX_ISSUE_PROCESSING_PATH_DEPTH as u32 + 8;
+        let path = processing_path(depth).await?;
+        assert_eq!(path.len(), MAX_ISSUE_PROCESSING_PATH_DEPTH);
+        // The items closest to the issue are kept.
+        assert!(path.last().unwrap().ends_with("/1.js"));
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
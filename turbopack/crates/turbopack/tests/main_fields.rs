@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+mod helpers;
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Value, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    reference_type::ReferenceType,
+    resolve::{
+        node::node_cjs_resolve_options,
+        options::{ResolveIntoPackage, ESM_MAIN_FIELDS},
+        parse::Request,
+        resolve, ResolveOptions,
+    },
+    source::Source,
+};
+
+use crate::helpers::fixture_root;
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+async fn resolve_package(
+    root: Vc<FileSystemPath>,
+    package: &str,
+    options: Vc<ResolveOptions>,
+) -> Result<RcStr> {
+    let result = resolve(
+        root,
+        Value::new(ReferenceType::Undefined),
+        Request::parse_string(package.into()),
+        options,
+    );
+    let source = result
+        .first_source()
+        .await?
+        .expect("expected the package to resolve");
+    Ok(source.ident().path().await?.path.clone())
+}
+
+#[tokio::test]
+async fn esm_main_fields_prefer_module() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("main_fields");
+        let cjs_options = node_cjs_resolve_options(root);
+        let esm_options =
+            cjs_options.with_main_fields(ESM_MAIN_FIELDS.into_iter().map(RcStr::from).collect());
+
+        assert_eq!(
+            resolve_package(root, "dual", cjs_options).await?,
+            "node_modules/dual/index.cjs"
+        );
+        assert_eq!(
+            resolve_package(root, "dual", esm_options).await?,
+            "node_modules/dual/index.mjs"
+        );
+
+        assert_eq!(
+            resolve_package(root, "legacy", cjs_options).await?,
+            "node_modules/legacy/index.js"
+        );
+        assert_eq!(
+            resolve_package(root, "legacy", esm_options).await?,
+            "node_modules/legacy/index.esm.js"
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn main_fields_keep_previous_fields() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root();
+        let options = node_cjs_resolve_options(root).with_main_fields(vec![
+            "jsnext:main".into(),
+            "...".into(),
+            "module".into(),
+        ]);
+
+        // the main fields are rewritten in place, so they are what resolving
+        // into a package actually uses
+        let fields = options
+            .await?
+            .into_package
+            .iter()
+            .filter_map(|item| match item {
+                ResolveIntoPackage::MainField { field } => Some(field.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["jsnext:main", "main", "module"]);
+
+        assert_eq!(
+            resolve_package(root, "legacy", options).await?,
+            "node_modules/legacy/index.esm.js"
+        );
+        assert_eq!(
+            resolve_package(root, "dual", options).await?,
+            "node_modules/dual/index.cjs"
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
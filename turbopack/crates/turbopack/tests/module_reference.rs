@@ -0,0 +1,144 @@
+#![cfg(test)]
+#![feature(arbitrary_self_types)]
+
+use std::borrow::Cow;
+
+use turbo_tasks::{
+    registry, FunctionId, RcStr, TurboTasks, ValueToString, Vc, VcValueTrait, VcValueType,
+};
+use turbo_tasks_fs::{File, FileSystem, FileSystemPath, VirtualFileSystem};
+use turbo_tasks_memory::MemoryBackend;
+use turbo_tasks_testing::{register, Registration};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    module::Module,
+    raw_module::RawModule,
+    reference::{
+        primary_referenced_modules, trivially_resolved_module, ModuleReference, ModuleReferences,
+        SingleModuleReference,
+    },
+    resolve::ModuleResolveResult,
+    virtual_source::VirtualSource,
+};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[turbo_tasks::value]
+struct TestModule {
+    path: Vc<FileSystemPath>,
+    references: Vec<Vc<Box<dyn ModuleReference>>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Module for TestModule {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        AssetIdent::from_path(self.path)
+    }
+
+    #[turbo_tasks::function]
+    fn references(&self) -> Vc<ModuleReferences> {
+        Vc::cell(self.references.clone())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for TestModule {
+    #[turbo_tasks::function]
+    fn content(&self) -> Vc<AssetContent> {
+        AssetContent::file(File::from("").into())
+    }
+}
+
+/// A reference which needs to be resolved by calling `resolve_reference`.
+#[turbo_tasks::value]
+struct TestReference {
+    module: Vc<Box<dyn Module>>,
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for TestReference {
+    #[turbo_tasks::function]
+    fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
+        ModuleResolveResult::module(self.module).cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for TestReference {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> Vc<RcStr> {
+        Vc::cell("test reference".into())
+    }
+}
+
+/// Returns the id of the `resolve_reference` implementation of `T`.
+fn resolve_reference_function_id<T: VcValueType>() -> FunctionId {
+    let key = (
+        <Box<dyn ModuleReference> as VcValueTrait>::get_trait_type_id(),
+        Cow::Borrowed("resolve_reference"),
+    );
+    *registry::get_value_type(T::get_value_type_id())
+        .get_trait_method(&key)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn single_module_reference_is_trivially_resolved() {
+    REGISTRATION.ensure_registered();
+    let tt = TurboTasks::new(MemoryBackend::default());
+    let stats = tt.backend().task_statistics().enable().clone();
+    tt.run_once(async move {
+        let root = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new()).root();
+        let raw_module = |name: &str| async move {
+            Vc::upcast::<Box<dyn Module>>(RawModule::new(Vc::upcast(VirtualSource::new(
+                root.join(name.into()),
+                AssetContent::file(File::from("").into()),
+            ))))
+            .resolve()
+            .await
+        };
+        let (single, other) = (
+            raw_module("single.js").await?,
+            raw_module("other.js").await?,
+        );
+
+        let single_reference = Vc::upcast::<Box<dyn ModuleReference>>(SingleModuleReference::new(
+            single,
+            Vc::cell("single".into()),
+        ));
+        let other_reference =
+            Vc::upcast::<Box<dyn ModuleReference>>(TestReference { module: other }.cell());
+
+        assert_eq!(
+            trivially_resolved_module(single_reference).await?,
+            Some(single)
+        );
+        assert_eq!(trivially_resolved_module(other_reference).await?, None);
+
+        let module = TestModule {
+            path: root.join("index.js".into()),
+            references: vec![single_reference, other_reference],
+        }
+        .cell();
+        assert_eq!(
+            *primary_referenced_modules(Vc::upcast(module)).await?,
+            vec![single, other]
+        );
+
+        // only the other reference scheduled a resolve task
+        assert_eq!(
+            stats.cache_hit_ratio(resolve_reference_function_id::<SingleModuleReference>()),
+            None
+        );
+        assert_eq!(
+            stats.cache_hit_ratio(resolve_reference_function_id::<TestReference>()),
+            Some(0.0)
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
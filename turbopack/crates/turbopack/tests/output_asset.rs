@@ -0,0 +1,111 @@
+#![cfg(test)]
+#![feature(arbitrary_self_types)]
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, TryJoinIterExt, Vc};
+use turbo_tasks_fs::{DiskFileSystem, File, FileSystem, FileSystemPath, VirtualFileSystem};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    output::{OutputAsset, OutputAssets},
+    reference::all_assets_from_entries,
+};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[turbo_tasks::value]
+struct TestAsset {
+    path: Vc<FileSystemPath>,
+    auxiliary_assets: Vec<Vc<Box<dyn OutputAsset>>>,
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for TestAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        AssetIdent::from_path(self.path)
+    }
+
+    #[turbo_tasks::function]
+    fn auxiliary_assets(&self) -> Vc<OutputAssets> {
+        Vc::cell(self.auxiliary_assets.clone())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for TestAsset {
+    #[turbo_tasks::function]
+    fn content(&self) -> Vc<AssetContent> {
+        AssetContent::file(File::from("").into())
+    }
+}
+
+async fn paths(assets: Vc<OutputAssets>) -> Result<Vec<RcStr>> {
+    let mut paths = assets
+        .await?
+        .iter()
+        .map(|asset| async move { Ok(asset.ident().path().await?.path.clone()) })
+        .try_join()
+        .await?;
+    paths.sort();
+    Ok(paths)
+}
+
+#[tokio::test]
+async fn all_assets_include_auxiliary_assets() {
+    run(&REGISTRATION, || async {
+        let root = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new()).root();
+        let declarations = TestAsset {
+            path: root.join("index.d.ts".into()),
+            auxiliary_assets: vec![],
+        }
+        .cell();
+        let module = TestAsset {
+            path: root.join("index.js".into()),
+            auxiliary_assets: vec![Vc::upcast(declarations)],
+        }
+        .cell();
+
+        let assets = all_assets_from_entries(OutputAssets::new(vec![Vc::upcast(module)]));
+        assert_eq!(
+            paths(assets).await?,
+            vec![RcStr::from("index.d.ts"), RcStr::from("index.js")]
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn emit_includes_auxiliary_assets() {
+    run(&REGISTRATION, || async {
+        let output = tempfile::tempdir()?;
+        let fs = DiskFileSystem::new(
+            "output".into(),
+            output.path().to_string_lossy().into(),
+            vec![],
+        );
+        let root = Vc::upcast::<Box<dyn FileSystem>>(fs).root();
+        let declarations = TestAsset {
+            path: root.join("index.d.ts".into()),
+            auxiliary_assets: vec![],
+        }
+        .cell();
+        let module = TestAsset {
+            path: root.join("index.js".into()),
+            auxiliary_assets: vec![Vc::upcast(declarations)],
+        }
+        .cell();
+
+        turbopack::emit_with_completion(Vc::upcast(module), root).await?;
+        assert!(output.path().join("index.js").is_file());
+        assert!(output.path().join("index.d.ts").is_file());
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+mod helpers;
+
+use turbo_tasks_fs::{glob::Glob, ReadGlobLimits};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::issue::{
+    read_glob::read_glob_or_emit_issue, IssueDescriptionExt, StyledString,
+};
+
+use crate::helpers::fixture_root;
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[tokio::test]
+async fn truncated_glob_emits_issue() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("read_glob");
+        let glob = Glob::new("**/*.txt".into());
+
+        let result = read_glob_or_emit_issue(root, glob, false, ReadGlobLimits::default_limits());
+        assert!(!*result.is_truncated().await?);
+        assert_eq!(*result.match_count().await?, 4);
+        assert!(result.peek_issues_with_path().await?.is_empty_ref());
+
+        let result = read_glob_or_emit_issue(
+            root,
+            glob,
+            false,
+            ReadGlobLimits {
+                max_depth: 64,
+                max_entries: 3,
+            }
+            .cell(),
+        );
+        assert!(*result.is_truncated().await?);
+        assert_eq!(*result.match_count().await?, 3);
+        let issues = result
+            .peek_issues_with_path()
+            .await?
+            .get_plain_issues()
+            .await?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].title,
+            StyledString::Text("Glob matched too many files".into())
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
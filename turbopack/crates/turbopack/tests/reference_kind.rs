@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+mod helpers;
+
+use turbo_tasks::Vc;
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack::module_options::{EcmascriptOptionsContext, ModuleOptionsContext};
+use turbopack_core::{
+    environment::{BrowserEnvironment, ExecutionEnvironment},
+    module::Module,
+    reference::{
+        primary_referenced_modules, primary_referenced_modules_with_types, AssetReferenceType,
+        ModuleReference,
+    },
+};
+
+use crate::helpers::{asset_context_for, fixture_root, paths, process};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+/// Workers are only analyzed for client rendering, so this uses a browser
+/// target.
+fn index_module() -> Vc<Box<dyn Module>> {
+    let asset_context = asset_context_for(
+        ExecutionEnvironment::Browser(
+            BrowserEnvironment {
+                dom: true,
+                web_worker: false,
+                service_worker: false,
+                browserslist_query: "last 1 Chrome versions".into(),
+            }
+            .cell(),
+        ),
+        ModuleOptionsContext {
+            ecmascript: EcmascriptOptionsContext {
+                enable_types: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        Default::default(),
+    );
+    process(
+        asset_context,
+        fixture_root("reference_kind").join("index.ts".into()),
+    )
+}
+
+#[tokio::test]
+async fn references_report_their_kind() {
+    run(&REGISTRATION, || async {
+        let module = index_module();
+
+        // CSS references are reported by the imported stylesheet, so this also
+        // collects the references of the referenced modules
+        let mut kinds = Vec::new();
+        for &module in [module]
+            .iter()
+            .chain(primary_referenced_modules(module).await?.iter())
+        {
+            for &reference in module.references().await?.iter() {
+                kinds.push(*reference.kind().await?);
+            }
+        }
+        for expected in [
+            AssetReferenceType::EsmImport,
+            AssetReferenceType::CjsRequire,
+            AssetReferenceType::DynamicImport,
+            AssetReferenceType::Css,
+            AssetReferenceType::Url,
+            AssetReferenceType::Worker,
+            AssetReferenceType::TypeOnly,
+        ] {
+            assert!(
+                kinds.contains(&expected),
+                "expected a reference of kind {expected:?}, got {kinds:?}"
+            );
+        }
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn type_only_references_can_be_excluded() {
+    run(&REGISTRATION, || async {
+        let module = index_module();
+
+        let all = paths(primary_referenced_modules(module)).await?;
+        assert!(all.contains(&"globals.d.ts".into()));
+
+        let runtime = paths(primary_referenced_modules_with_types(module, false)).await?;
+        assert!(!runtime.contains(&"globals.d.ts".into()));
+        for path in ["asset.txt", "cjs.js", "dynamic.js", "esm.js", "styles.css"] {
+            assert!(
+                runtime.contains(&path.into()),
+                "missing {path} in {runtime:?}"
+            );
+        }
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
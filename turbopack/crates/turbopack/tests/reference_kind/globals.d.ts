@@ -0,0 +1 @@
+declare const GLOBAL: string;
@@ -0,0 +1,10 @@
+/// <reference path="./globals.d.ts" />
+import { a } from "./esm.js";
+import "./styles.css";
+
+const b = require("./cjs.js");
+const c = import("./dynamic.js");
+const d = new URL("./asset.txt", import.meta.url);
+const e = new Worker(new URL("./worker.js", import.meta.url));
+
+console.log(a, b, c, d, e);
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use turbo_tasks::Vc;
+use turbo_tasks_fs::{File, FileSystem, VirtualFileSystem};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    asset::AssetContent,
+    resolve::{ExternalType, RequestKey, ResolveResult, ResolveResultItem},
+    source::Source,
+    virtual_source::VirtualSource,
+};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+#[tokio::test]
+async fn first_source() {
+    run(&REGISTRATION, || async {
+        let root = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new()).root();
+        let source = |name: &str| {
+            Vc::upcast::<Box<dyn Source>>(VirtualSource::new(
+                root.join(name.into()),
+                AssetContent::file(File::from("").into()),
+            ))
+        };
+        let (index, fallback) = (source("index.js"), source("fallback.js"));
+
+        let single = ResolveResult::source(index);
+        assert_eq!(single.first_source_ref(), Some(index));
+
+        let mut alternatives = ResolveResult::primary_with_key(
+            RequestKey::new("external".into()),
+            ResolveResultItem::External("external".into(), ExternalType::CommonJs),
+        );
+        alternatives.merge_alternatives(&ResolveResult::source_with_key(
+            RequestKey::new("./index.js".into()),
+            index,
+        ));
+        alternatives.merge_alternatives(&ResolveResult::source_with_key(
+            RequestKey::new("./fallback.js".into()),
+            fallback,
+        ));
+        assert_eq!(alternatives.first_source_ref(), Some(index));
+
+        assert_eq!(ResolveResult::unresolveable().first_source_ref(), None);
+        assert_eq!(
+            ResolveResult::primary(ResolveResultItem::Ignore).first_source_ref(),
+            None
+        );
+        assert_eq!(
+            *ResolveResult::primary(ResolveResultItem::Empty)
+                .cell()
+                .first_source()
+                .await?,
+            None
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+mod helpers;
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::environment::{
+    BrowserEnvironment, ExecutionEnvironment, NodeJsEnvironment, NodeJsVersion,
+};
+
+use crate::helpers::{asset_context_for, fixture_root, process, referenced_paths};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+fn node(version: &str) -> ExecutionEnvironment {
+    ExecutionEnvironment::NodeJsLambda(
+        NodeJsEnvironment {
+            node_version: NodeJsVersion::Static(Vc::cell(version.into())).cell(),
+            ..Default::default()
+        }
+        .cell(),
+    )
+}
+
+async fn referenced_polyfills(execution: ExecutionEnvironment) -> Result<Vec<RcStr>> {
+    let root = fixture_root("runtime_capabilities");
+    let asset_context = asset_context_for(execution, Default::default(), Default::default());
+    referenced_paths(process(asset_context, root.join("index.js".into()))).await
+}
+
+#[tokio::test]
+async fn available_globals_eliminate_polyfills() {
+    run(&REGISTRATION, || async {
+        // `fetch` is a global since Node.js 18.
+        assert_eq!(
+            referenced_polyfills(node("16.0.0")).await?,
+            vec![RcStr::from("fetch-polyfill.js")]
+        );
+        assert_eq!(
+            referenced_polyfills(node("18.17.0")).await?,
+            Vec::<RcStr>::new()
+        );
+
+        // Only Node.js targets fold the checks.
+        let browser = ExecutionEnvironment::Browser(
+            BrowserEnvironment {
+                dom: true,
+                web_worker: false,
+                service_worker: false,
+                browserslist_query: "last 1 Chrome versions".into(),
+            }
+            .cell(),
+        );
+        assert_eq!(
+            referenced_polyfills(browser).await?,
+            vec![
+                RcStr::from("buffer-polyfill.js"),
+                RcStr::from("fetch-polyfill.js")
+            ]
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap();
+}
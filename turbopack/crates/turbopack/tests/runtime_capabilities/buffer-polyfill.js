@@ -0,0 +1 @@
+globalThis.Buffer = Uint8Array;
@@ -0,0 +1 @@
+globalThis.fetch = () => Promise.reject(new Error("not implemented"));
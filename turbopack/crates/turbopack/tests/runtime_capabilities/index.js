@@ -0,0 +1,9 @@
+if (typeof fetch === "undefined") {
+  require("./fetch-polyfill");
+}
+
+if (typeof Buffer === "undefined") {
+  require("./buffer-polyfill");
+}
+
+console.log(typeof fetch, typeof Buffer);
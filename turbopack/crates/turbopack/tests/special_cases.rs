@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+mod helpers;
+
+use turbo_tasks::Vc;
+use turbo_tasks_fs::{glob::Glob, FileSystemPath};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack::module_options::{EcmascriptOptionsContext, ModuleOptionsContext};
+use turbopack_core::module::Module;
+use turbopack_ecmascript::{SpecialCase, SpecialCases};
+use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+
+use crate::helpers::{asset_context, fixture_root, process, referenced_paths};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+fn module(
+    root: Vc<FileSystemPath>,
+    path: &str,
+    special_cases: Option<Vc<SpecialCases>>,
+) -> Vc<Box<dyn Module>> {
+    let asset_context = asset_context(
+        ModuleOptionsContext {
+            ecmascript: EcmascriptOptionsContext {
+                special_cases,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ResolveOptionsContext {
+            enable_node_modules: Some(root),
+            ..Default::default()
+        },
+    );
+    process(asset_context, root.join(path.into()))
+}
+
+#[tokio::test]
+async fn registered_special_case_adds_references() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("special_cases");
+        let special_cases = SpecialCases::empty().register(SpecialCase::new(
+            Glob::new("node_modules/pkg/index.js".into()),
+            vec!["./data.json".into()],
+        ));
+
+        let paths = referenced_paths(module(
+            root,
+            "node_modules/pkg/index.js",
+            Some(special_cases),
+        ))
+        .await?;
+        assert!(
+            paths
+                .iter()
+                .any(|path| path == "node_modules/pkg/data.json"),
+            "expected a reference to node_modules/pkg/data.json, got {paths:?}"
+        );
+
+        // the data file can't be found by the analysis alone
+        let paths = referenced_paths(module(root, "node_modules/pkg/index.js", None)).await?;
+        assert!(!paths
+            .iter()
+            .any(|path| path == "node_modules/pkg/data.json"));
+
+        // modules not matching the pattern are unaffected
+        let paths = referenced_paths(module(root, "index.js", Some(special_cases))).await?;
+        assert!(!paths
+            .iter()
+            .any(|path| path == "node_modules/pkg/data.json"));
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
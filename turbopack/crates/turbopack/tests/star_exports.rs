@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+mod helpers;
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    issue::{IssueDescriptionExt, StyledString},
+    module::Module,
+};
+use turbopack_ecmascript::{chunk::EcmascriptExports, EcmascriptModuleAsset};
+
+use crate::helpers::{asset_context, fixture_root, process};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+async fn export_names(module: Vc<Box<dyn Module>>) -> Result<Vec<RcStr>> {
+    let module = Vc::try_resolve_downcast_type::<EcmascriptModuleAsset>(module)
+        .await?
+        .expect("expected an ecmascript module");
+    let EcmascriptExports::EsmExports(exports) = *module.analyze().await?.exports.await? else {
+        panic!("expected ESM exports");
+    };
+    Ok(exports
+        .expand_exports()
+        .await?
+        .exports
+        .keys()
+        .cloned()
+        .collect())
+}
+
+#[tokio::test]
+async fn conflicting_star_reexports_are_omitted_with_a_warning() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("star_exports");
+        let asset_context = asset_context(Default::default(), Default::default());
+        let module = process(asset_context, root.join("reexport.js".into()));
+
+        // `foo` is ambiguous, `bar` is exported explicitly and takes precedence
+        assert_eq!(
+            export_names(module).await?,
+            vec![
+                RcStr::from("bar"),
+                RcStr::from("onlyA"),
+                RcStr::from("onlyB")
+            ]
+        );
+
+        let references = module.references();
+        references.strongly_consistent().await?;
+        let issues = references
+            .peek_issues_with_path()
+            .await?
+            .get_plain_issues()
+            .await?;
+        assert_eq!(issues.len(), 1, "expected a single warning");
+        assert_eq!(
+            issues[0].title,
+            StyledString::Text("unexpected export *".into())
+        );
+        let Some(StyledString::Text(description)) = &issues[0].description else {
+            panic!("expected a text description");
+        };
+        assert!(description.starts_with("The export foo is provided by multiple modules"));
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,3 @@
+export const foo = "a-foo";
+export const bar = "a-bar";
+export const onlyA = "a";
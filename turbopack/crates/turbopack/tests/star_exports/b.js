@@ -0,0 +1,3 @@
+export const foo = "b-foo";
+export const bar = "b-bar";
+export const onlyB = "b";
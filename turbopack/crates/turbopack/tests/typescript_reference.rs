@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+mod helpers;
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack::module_options::{EcmascriptOptionsContext, ModuleOptionsContext};
+use turbopack_core::{module::Module, reference::primary_referenced_modules};
+use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+
+use crate::helpers::{asset_context, fixture_root, process};
+
+static REGISTRATION: Registration = register!(turbopack::register);
+
+async fn referenced_modules(
+    module: Vc<Box<dyn Module>>,
+) -> Result<Vec<(RcStr, Vc<Box<dyn Module>>)>> {
+    let mut modules = Vec::new();
+    for &referenced in primary_referenced_modules(module).await?.iter() {
+        modules.push((referenced.ident().path().await?.path.clone(), referenced));
+    }
+    Ok(modules)
+}
+
+#[tokio::test]
+async fn references_resolve_relative_to_the_referring_file() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root("typescript_reference");
+        let asset_context = asset_context(
+            ModuleOptionsContext {
+                ecmascript: EcmascriptOptionsContext {
+                    enable_types: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ResolveOptionsContext {
+                enable_node_modules: Some(root),
+                enable_typescript: true,
+                ..Default::default()
+            },
+        );
+        let module = process(asset_context, root.join("src/nested/index.ts".into()));
+
+        let references = referenced_modules(module).await?;
+        let paths = references.iter().map(|(path, _)| path).collect::<Vec<_>>();
+        // `/// <reference path="..." />` is relative to the file containing it
+        assert!(
+            paths.iter().any(|path| *path == "src/nested/globals.d.ts"),
+            "expected a reference to src/nested/globals.d.ts, got {paths:?}"
+        );
+        assert!(!paths.iter().any(|path| *path == "globals.d.ts"));
+
+        // requests in the tsconfig are relative to the tsconfig
+        let (_, tsconfig) = references
+            .iter()
+            .find(|(path, _)| path == "tsconfig.json")
+            .expect("expected a reference to tsconfig.json");
+        let tsconfig_paths = referenced_modules(*tsconfig)
+            .await?
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert!(
+            tsconfig_paths.iter().any(|path| path == "setup.js"),
+            "expected the tsconfig to reference setup.js, got {tsconfig_paths:?}"
+        );
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
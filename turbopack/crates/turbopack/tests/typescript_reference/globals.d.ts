@@ -0,0 +1 @@
+declare const root: string;
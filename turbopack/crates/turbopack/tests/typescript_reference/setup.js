@@ -0,0 +1 @@
+globalThis.setup = true;
@@ -0,0 +1 @@
+declare const nested: string;
@@ -0,0 +1,3 @@
+/// <reference path="./globals.d.ts" />
+
+export const value = nested;